@@ -1,1073 +1,653 @@
-use std::collections::HashMap;
+mod button;
+mod complicated_wires;
+mod edgework;
+mod header;
+mod keypad;
+mod knobs;
+mod mazes;
+mod memory;
+mod morse_code;
+mod needy;
+mod passwords;
+mod simon_says;
+mod whos_on_first;
+mod wire_sequences;
+mod wires;
 
-use egui::{
-    lerp, remap_clamp, Button, Color32, Frame, Grid, Pos2, RichText, Slider,
-    TextEdit, Vec2,
-};
-use strum::IntoEnumIterator;
-use strum_macros::{AsRefStr, EnumIter};
+use std::path::PathBuf;
 
-#[derive(EnumIter, AsRefStr)]
-enum Module {
-    Menu,
-    Wires,
-    Button,
-    Keypad,
-    SimonSays,
-    WhosOnFirst,
-    Memory,
-    MorseCode,
-    ComplicatedWires,
-    WireSequences,
-    Mazes,
-    Passwords,
-    Knobs,
-}
+#[cfg(target_os = "android")]
+use egui::{Button, Vec2};
+use egui::{Color32, Grid, RichText};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
-#[derive(AsRefStr, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
-enum KeypadButton {
-    None,
-    O,
-    A,
-    Lambda,
-    N,
-    Person,
-    H,
-    AntiC,
-    Euro,
-    Q,
-    EmptyStar,
-    Question,
-    Copyright,
-    W,
-    X,
-    R,
-    N6,
-    Paragraph,
-    B,
-    Smile,
-    Trident,
-    C,
-    Snake,
-    FilledStar,
-    Puzzle,
-    AE,
-    Yot,
-    Omega,
+use crate::solver::edgework::{Edgework, Facts};
+use crate::solver::ordinal;
+
+/// Where a module appears in the menu.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Category {
+    Regular,
+    Needy,
 }
 
-impl KeypadButton {
+impl Category {
+    const ALL: [Category; 2] = [Category::Regular, Category::Needy];
+
     fn name(&self) -> &str {
         match self {
-            KeypadButton::AntiC => "Anti-C",
-            KeypadButton::EmptyStar => "Empty Stat",
-            KeypadButton::N6 => "6",
-            KeypadButton::FilledStar => "Filled Star",
-            KeypadButton::Yot => "Yot",
-            k => k.as_ref(),
+            Category::Regular => "Modules",
+            Category::Needy => "Needy Modules",
         }
     }
 }
 
+/// Information about the whole bomb that every module screen can read.
 #[derive(Default)]
-struct Memory {
-    position1: u8,
-    position2: u8,
-    label1: u8,
-    label2: u8,
-    label3: u8,
-    label4: u8,
+pub struct Bomb {
+    pub edgework: Edgework,
+    /// Derived from `edgework` whenever it changes.
+    pub facts: Facts,
+    pub strikes: u8,
+    pub timer: header::Timer,
 }
 
-#[derive(Default)]
-struct WireSequence {
-    red: u8,
-    blue: u8,
-    black: u8,
+impl Bomb {
+    /// Changes the strike count, keeping the timer running at the matching
+    /// speed.
+    pub fn set_strikes(&mut self, strikes: u8) {
+        self.strikes = strikes.min(header::MAX_STRIKES);
+        self.timer.set_strikes(self.strikes);
+    }
 }
 
-#[derive(Clone, Copy)]
-enum SimonColor {
-    Red,
-    Blue,
-    Green,
-    Yellow,
+/// Saving and restoring a screen's state between runs, implemented for every
+/// screen that can be serialized.
+pub trait Persist {
+    fn save(&self) -> serde_json::Value;
+
+    /// Restores state returned by `save`, keeping the current state if the
+    /// saved one no longer fits.
+    fn load(&mut self, value: serde_json::Value);
 }
 
-impl SimonColor {
-    fn color32(&self) -> Color32 {
-        match self {
-            SimonColor::Red => Color32::RED,
-            SimonColor::Blue => Color32::BLUE,
-            SimonColor::Green => Color32::GREEN,
-            SimonColor::Yellow => Color32::YELLOW,
+impl<T: Serialize + DeserializeOwned> Persist for T {
+    fn save(&self) -> serde_json::Value {
+        serde_json::to_value(self).expect("screen state is plain data")
+    }
+
+    fn load(&mut self, value: serde_json::Value) {
+        match serde_json::from_value(value) {
+            Ok(state) => *self = state,
+            Err(err) => log::warn!("Discarding saved screen state: {err}"),
         }
     }
 }
 
-#[derive(Default)]
-struct SimonSays {
-    strikes: u8,
-    vowel: bool,
-    entered: Vec<SimonColor>,
-}
+/// A screen that helps the expert with one kind of module. Every module on
+/// the bomb gets its own screen, whose state survives returning to the menu
+/// until it is reset and is saved whenever it changes.
+pub trait ModuleScreen: Persist {
+    fn name(&self) -> &str;
 
-impl SimonSays {
-    const TABLE: [SimonColor; 24] = [
-        SimonColor::Blue, // No vowel, 0 strikes
-        SimonColor::Yellow,
-        SimonColor::Green,
-        SimonColor::Red,
-        SimonColor::Red, // No vowel, 1 strike
-        SimonColor::Blue,
-        SimonColor::Yellow,
-        SimonColor::Green,
-        SimonColor::Yellow, // No vowel, 2 strikes
-        SimonColor::Green,
-        SimonColor::Blue,
-        SimonColor::Red,
-        SimonColor::Blue, // Vowel, 0 strikes
-        SimonColor::Red,
-        SimonColor::Yellow,
-        SimonColor::Green,
-        SimonColor::Yellow, // Vowel, 1 strike
-        SimonColor::Green,
-        SimonColor::Blue,
-        SimonColor::Red,
-        SimonColor::Green, // Vowel, 2 strikes
-        SimonColor::Red,
-        SimonColor::Yellow,
-        SimonColor::Blue,
-    ];
-    fn convert(&self, color: &SimonColor) -> SimonColor {
-        Self::TABLE[(if self.vowel { 12 } else { 0 }
-            + self.strikes * 4
-            + match color {
-                SimonColor::Red => 0,
-                SimonColor::Blue => 1,
-                SimonColor::Green => 2,
-                SimonColor::Yellow => 3,
-            }) as usize]
+    fn category(&self) -> Category;
+
+    /// Which needy module this is, for the dashboard to keep its countdown.
+    fn needy(&self) -> Option<needy::NeedyKind> {
+        None
     }
+
+    /// Draws the screen below the shared Menu, Reset, Edgework and Needy buttons.
+    fn ui(&mut self, ui: &mut egui::Ui, bomb: &Bomb);
+
+    /// Clears everything entered for this module.
+    fn reset(&mut self);
+
+    /// Whether the entered information is enough to finish the module.
+    fn is_solved(&self, bomb: &Bomb) -> bool;
+}
+
+/// Creates a screen for one kind of module.
+type Constructor = fn() -> Box<dyn ModuleScreen>;
+
+/// Every kind of module in menu order.
+fn registry() -> Vec<Constructor> {
+    vec![
+        || Box::<wires::Wires>::default(),
+        || Box::<button::Button>::default(),
+        || Box::<keypad::Keypad>::default(),
+        || Box::<simon_says::SimonSays>::default(),
+        || Box::<whos_on_first::WhosOnFirst>::default(),
+        || Box::<memory::Memory>::default(),
+        || Box::<morse_code::MorseCode>::default(),
+        || Box::<complicated_wires::ComplicatedWires>::default(),
+        || Box::<wire_sequences::WireSequences>::default(),
+        || Box::<mazes::Mazes>::default(),
+        || Box::<passwords::Passwords>::default(),
+        || Box::<knobs::Knobs>::default(),
+        || Box::<needy::VentingGas>::default(),
+        || Box::<needy::CapacitorDischarge>::default(),
+    ]
 }
 
-fn whos_translate(word: &str) -> String {
-    if word.chars().count() > 4 {
-        let mut s = String::from(word.chars().nth(0).unwrap());
-        s += &word[word.char_indices().nth_back(2).unwrap().0..];
-        s
-    } else {
-        word.to_owned()
+/// A kind of module the bomb can have.
+struct Kind {
+    name: String,
+    category: Category,
+    needy: Option<needy::NeedyKind>,
+    create: Constructor,
+}
+
+/// One module on the bomb, with its own screen state.
+struct Module {
+    /// Index into `Application::kinds`.
+    kind: usize,
+    screen: Box<dyn ModuleScreen>,
+    /// Whether the defuser reported the module as disarmed.
+    solved: bool,
+    /// How many strikes the module caused.
+    strikes: u8,
+    /// Only runs for needy modules.
+    countdown: needy::Countdown,
+}
+
+impl Module {
+    fn new(kind: usize, kinds: &[Kind]) -> Self {
+        Self {
+            kind,
+            screen: (kinds[kind].create)(),
+            solved: false,
+            strikes: 0,
+            countdown: needy::Countdown::default(),
+        }
     }
 }
 
 #[cfg(target_os = "android")]
 const KEYBOARD: [char; 28] = [
-    'Q', 'W', 'E', 'R', 'T', 'Y', 'U', 'I', 'O', 'P', 'A', 'S', 'D', 'F', 'G', 'H', 'J', 'K',
-    'L', 'Z', 'X', 'C', 'V', 'B', 'N', 'M', '\'', '?'
+    'Q', 'W', 'E', 'R', 'T', 'Y', 'U', 'I', 'O', 'P', 'A', 'S', 'D', 'F', 'G', 'H', 'J', 'K', 'L',
+    'Z', 'X', 'C', 'V', 'B', 'N', 'M', '\'', '?',
 ];
 
 #[cfg(target_os = "android")]
 fn keyboard(ui: &mut egui::Ui, string: &mut String) -> bool {
-    Grid::new("keyboard").spacing((0.0, 0.0)).min_col_width(0.0).show(ui, |ui| {
-        let mut changed = false;
-        for i in 0usize..28 {
-            if ui.add(Button::new(RichText::new(KEYBOARD[i])).min_size(Vec2::new(30.0, 10.0)).rounding(0.0)).clicked() {
-                string.push(KEYBOARD[i]);
-                changed = true;
-            }
-            if i == 9 {
-                ui.end_row();
-            } else if i == 18 {
-                if ui.add(Button::new(RichText::new("\u{2190}")).min_size(Vec2::new(30.0, 10.0)).rounding(0.0)).clicked() {
-                    string.pop();
+    Grid::new("keyboard")
+        .spacing((0.0, 0.0))
+        .min_col_width(0.0)
+        .show(ui, |ui| {
+            let mut changed = false;
+            for i in 0usize..28 {
+                if ui
+                    .add(
+                        Button::new(RichText::new(KEYBOARD[i]))
+                            .min_size(Vec2::new(30.0, 10.0))
+                            .rounding(0.0),
+                    )
+                    .clicked()
+                {
+                    string.push(KEYBOARD[i]);
                     changed = true;
                 }
-                ui.end_row();
-            } else if i == 25 {
-                if ui.add(Button::new(RichText::new("\u{2588}").size(10.0)).min_size(Vec2::new(30.0, 10.0)).rounding(0.0)).clicked() {
-                    string.push(' ');
+                if i == 9 {
+                    ui.end_row();
+                } else if i == 18 {
+                    if ui
+                        .add(
+                            Button::new(RichText::new("\u{2190}"))
+                                .min_size(Vec2::new(30.0, 10.0))
+                                .rounding(0.0),
+                        )
+                        .clicked()
+                    {
+                        string.pop();
+                        changed = true;
+                    }
+                    ui.end_row();
+                } else if i == 25 {
+                    if ui
+                        .add(
+                            Button::new(RichText::new("\u{2588}").size(10.0))
+                                .min_size(Vec2::new(30.0, 10.0))
+                                .rounding(0.0),
+                        )
+                        .clicked()
+                    {
+                        string.push(' ');
+                        changed = true;
+                    }
+                }
+            }
+            changed
+        })
+        .inner
+}
+
+#[cfg(target_os = "android")]
+fn digits(ui: &mut egui::Ui, string: &mut String) -> bool {
+    Grid::new("digits")
+        .spacing((0.0, 0.0))
+        .min_col_width(0.0)
+        .show(ui, |ui| {
+            let mut changed = false;
+            for digit in '0'..='9' {
+                if ui
+                    .add(
+                        Button::new(RichText::new(digit))
+                            .min_size(Vec2::new(30.0, 10.0))
+                            .rounding(0.0),
+                    )
+                    .clicked()
+                {
+                    string.push(digit);
                     changed = true;
                 }
             }
-        }
-        changed
-    }).inner
+            changed
+        })
+        .inner
+}
+
+/// The name of the file holding the saved state in the storage directory.
+const STATE_FILE: &str = "state.json";
+
+/// A module as written to storage, naming its kind so that kinds can be added
+/// or reordered without losing the rest.
+#[derive(Serialize, Deserialize)]
+struct SavedModule {
+    kind: String,
+    solved: bool,
+    strikes: u8,
+    #[serde(default)]
+    countdown: needy::Countdown,
+    state: serde_json::Value,
+}
+
+/// Everything written to storage.
+#[derive(Default, Serialize, Deserialize)]
+#[serde(default)]
+struct Saved {
+    current: Option<usize>,
+    edgework: Edgework,
+    strikes: u8,
+    timer: header::Timer,
+    modules: Vec<SavedModule>,
 }
 
 pub struct Application {
-    module: Module,
-    state: usize,
-    label: String,
+    kinds: Vec<Kind>,
+    /// The modules on the bomb, grouped by kind in menu order.
+    modules: Vec<Module>,
+    /// Index into `modules`, or `None` for the menu.
+    current: Option<usize>,
+    /// Whether the list of modules on the bomb is being edited.
+    setting_up: bool,
+    /// Whether the edgework screen is shown on top of `current`.
+    editing_edgework: bool,
+    /// Whether the needy dashboard is shown on top of `current`.
+    showing_needy: bool,
+    header: header::Header,
+    bomb: Bomb,
+    /// Where the state is saved, if anywhere.
+    storage: Option<PathBuf>,
+    /// The state as last written, to skip writing it again unchanged.
+    saved: String,
     painter: egui::Painter,
-    keypad: HashMap<KeypadButton, u8>,
-    simon_says: SimonSays,
-    whos_on_first_positions: HashMap<String, usize>,
-    whos_on_first_buttons: HashMap<String, Vec<String>>,
-    whos_on_first: [String; 7],
-    memory: Memory,
-    wire_sequence: WireSequence,
-    password: [String; 5],
 }
 
 impl Application {
-    const KEYPAD_BUTTONS: [[KeypadButton; 5]; 6] = [
-        [
-            KeypadButton::O,
-            KeypadButton::A,
-            KeypadButton::Lambda,
-            KeypadButton::N,
-            KeypadButton::Person,
-        ],
-        [
-            KeypadButton::H,
-            KeypadButton::AntiC,
-            KeypadButton::Euro,
-            KeypadButton::Q,
-            KeypadButton::EmptyStar,
-        ],
-        [
-            KeypadButton::Question,
-            KeypadButton::Copyright,
-            KeypadButton::W,
-            KeypadButton::X,
-            KeypadButton::R,
-        ],
-        [
-            KeypadButton::N6,
-            KeypadButton::Paragraph,
-            KeypadButton::B,
-            KeypadButton::Smile,
-            KeypadButton::Trident,
-        ],
-        [
-            KeypadButton::C,
-            KeypadButton::Snake,
-            KeypadButton::FilledStar,
-            KeypadButton::Puzzle,
-            KeypadButton::AE,
-        ],
-        [
-            KeypadButton::Yot,
-            KeypadButton::Omega,
-            KeypadButton::None,
-            KeypadButton::None,
-            KeypadButton::None,
-        ],
-    ];
-    const KEYPAD_COLUMNS: [[KeypadButton; 7]; 6] = [
-        [
-            KeypadButton::O,
-            KeypadButton::A,
-            KeypadButton::Lambda,
-            KeypadButton::N,
-            KeypadButton::Person,
-            KeypadButton::H,
-            KeypadButton::AntiC,
-        ],
-        [
-            KeypadButton::Euro,
-            KeypadButton::O,
-            KeypadButton::AntiC,
-            KeypadButton::Q,
-            KeypadButton::EmptyStar,
-            KeypadButton::H,
-            KeypadButton::Question,
-        ],
-        [
-            KeypadButton::Copyright,
-            KeypadButton::W,
-            KeypadButton::Q,
-            KeypadButton::X,
-            KeypadButton::R,
-            KeypadButton::Lambda,
-            KeypadButton::EmptyStar,
-        ],
-        [
-            KeypadButton::N6,
-            KeypadButton::Paragraph,
-            KeypadButton::B,
-            KeypadButton::Person,
-            KeypadButton::X,
-            KeypadButton::Question,
-            KeypadButton::Smile,
-        ],
-        [
-            KeypadButton::Trident,
-            KeypadButton::Smile,
-            KeypadButton::B,
-            KeypadButton::C,
-            KeypadButton::Paragraph,
-            KeypadButton::Snake,
-            KeypadButton::FilledStar,
-        ],
-        [
-            KeypadButton::N6,
-            KeypadButton::Euro,
-            KeypadButton::Puzzle,
-            KeypadButton::AE,
-            KeypadButton::Trident,
-            KeypadButton::Yot,
-            KeypadButton::Omega,
-        ],
-    ];
-    const COMPLICATED_WIRES: [&str; 16] = [
-        "ALWAYS",
-        "NEVER",
-        "ALWAYS",
-        "2+ BATTERIES",
-        "SERIAL NUMBER ENDS EVEN",
-        "PARALLEL PORT",
-        "NEVER",
-        "PARALLEL PORT",
-        "SERIAL NUMBER ENDS EVEN",
-        "2+ BATTERIES",
-        "ALWAYS",
-        "2+ BATTERIES",
-        "SERIAL NUMBER ENDS EVEN",
-        "SERIAL NUMBER ENDS EVEN",
-        "PARALLEL PORT",
-        "NEVER",
-    ];
-    const WIRE_SEQUENCE: [&str; 27] = [
-        "C", "B", "A", "AC", "B", "AC", "ABC", "AB", "B", // Red
-        "B", "AC", "B", "A", "B", "BC", "C", "AC", "A", // Blue
-        "ABC", "AC", "B", "AC", "B", "BC", "AB", "C", "C", // Black
-    ];
-    const PASSWORDS: [&str; 35] = [
-        "ABOUT", "AFTER", "AGAIN", "BELOW", "COULD", "EVERY", "FIRST", "FOUND", "GREAT", "HOUSE",
-        "LARGE", "LEARN", "NEVER", "OTHER", "PLACE", "PLANT", "POINT", "RIGHT", "SMALL", "SOUND",
-        "SPELL", "STILL", "STUDY", "THEIR", "THERE", "THESE", "THINK", "THINK", "THREE", "WATER",
-        "WHERE", "WHICH", "WORLD", "WOULD", "WRITE",
-    ];
-
-    pub fn new(ctx: &egui::Context) -> Self {
-        let mut whos_on_first_positions: HashMap<String, usize> = HashMap::new();
-        for (label, position) in [
-            // Positions:
-            // 0 1
-            // 2 3
-            // 4 5
-            ("", 4),
-            ("BLANK", 3),
-            ("C", 1),
-            ("CEE", 5),
-            ("DISPLAY", 5),
-            ("FIRST", 1),
-            ("HOLD ON", 5),
-            ("LEAD", 5),
-            ("LED", 2),
-            ("LEED", 4),
-            ("NO", 5),
-            ("NOTHING", 2),
-            ("OKAY", 1),
-            ("READ", 3),
-            ("RED", 3),
-            ("REED", 4),
-            ("SAYS", 5),
-            ("SEE", 5),
-            ("THEIR", 3),
-            ("THERE", 5),
-            ("THEY ARE", 2),
-            ("THEY'RE", 4),
-            ("UR", 0),
-            ("YES", 2),
-            ("YOU", 3),
-            ("YOU ARE", 5),
-            ("YOU'RE", 3),
-            ("YOUR", 3),
-        ] {
-            whos_on_first_positions.insert(whos_translate(label), position);
-        }
+    /// Creates the application, restoring the state saved in the `storage`
+    /// directory.
+    pub fn new(ctx: &egui::Context, storage: Option<PathBuf>) -> Self {
+        let mut fonts = egui::FontDefinitions::default();
+        keypad::add_font(&mut fonts);
+        ctx.set_fonts(fonts);
 
-        let mut whos_on_first_buttons: HashMap<String, Vec<String>> = HashMap::new();
-        for (label, v) in [
-            ("BLANK", vec!["WAIT", "RIGHT", "OKAY", "MIDDLE", "BLANK"]),
-            (
-                "DONE",
-                vec![
-                    "SURE", "UH HUH", "NEXT", "WHAT?", "YOUR", "UR", "YOU'RE", "HOLD", "LIKE",
-                    "YOU", "U", "YOU ARE", "UH UH", "DONE",
-                ],
-            ),
-            (
-                "FIRST",
-                vec![
-                    "LEFT", "OKAY", "YES", "MIDDLE", "NO", "RIGHT", "NOTHING", "UHHH", "WAIT",
-                    "READY", "BLANK", "WHAT", "PRESS", "FIRST",
-                ],
-            ),
-            (
-                "HOLD",
-                vec![
-                    "YOU ARE", "U", "DONE", "UH UH", "YOU", "UR", "SURE", "WHAT?", "YOU'RE",
-                    "NEXT", "HOLD",
-                ],
-            ),
-            ("LEFT", vec!["RIGHT", "LEFT"]),
-            (
-                "LIKE",
-                vec![
-                    "YOU'RE", "NEXT", "U", "UR", "HOLD", "DONE", "UH UH", "WHAT?", "UH HUH", "YOU",
-                    "LIKE",
-                ],
-            ),
-            (
-                "MIDDLE",
-                vec![
-                    "BLANK", "READY", "OKAY", "WHAT", "NOTHING", "PRESS", "NO", "WAIT", "LEFT",
-                    "MIDDLE",
-                ],
-            ),
-            (
-                "NEXT",
-                vec!["WHAT?", "UH HUH", "UH UH", "YOUR", "HOLD", "SURE", "NEXT"],
-            ),
-            (
-                "NO",
-                vec![
-                    "BLANK", "UHHH", "WAIT", "FIRST", "WHAT", "READY", "RIGHT", "YES", "NOTHING",
-                    "LEFT", "PRESS", "OKAY", "NO",
-                ],
-            ),
-            (
-                "NOTHING",
-                vec![
-                    "UHHH", "RIGHT", "OKAY", "MIDDLE", "YES", "BLANK", "NO", "PRESS", "LEFT",
-                    "WHAT", "WAIT", "FIRST", "NOTHING",
-                ],
-            ),
-            (
-                "OKAY",
-                vec![
-                    "MIDDLE", "NO", "FIRST", "YES", "UHHH", "NOTHING", "WAIT", "OKAY",
-                ],
-            ),
-            ("PRESS", vec!["RIGHT", "MIDDLE", "YES", "READY", "PRESS"]),
-            (
-                "RIGHT",
-                vec![
-                    "YES", "NOTHING", "READY", "PRESS", "NO", "WAIT", "WHAT", "RIGHT",
-                ],
-            ),
-            (
-                "SURE",
-                vec![
-                    "YOU ARE", "DONE", "LIKE", "YOU'RE", "YOU", "HOLD", "UH HUH", "UR", "SURE",
-                ],
-            ),
-            (
-                "U",
-                vec![
-                    "UH HUH", "SURE", "NEXT", "WHAT?", "YOU'RE", "UR", "UH UH", "DONE", "U",
-                ],
-            ),
-            ("UH HUH", vec!["UH HUH"]),
-            (
-                "UH UH",
-                vec!["UR", "U", "YOU ARE", "YOU'RE", "NEXT", "UH UH"],
-            ),
-            (
-                "UHHH",
-                vec![
-                    "READY", "NOTHING", "LEFT", "WHAT", "OKAY", "YES", "RIGHT", "NO", "PRESS",
-                    "BLANK", "UHHH",
-                ],
-            ),
-            ("UR", vec!["DONE", "U", "UR"]),
-            (
-                "WAIT",
-                vec![
-                    "UHHH", "NO", "BLANK", "OKAY", "YES", "LEFT", "FIRST", "PRESS", "WHAT", "WAIT",
-                ],
-            ),
-            ("WHAT", vec!["UHHH", "WHAT"]),
-            (
-                "WHAT?",
-                vec![
-                    "YOU", "HOLD", "YOU'RE", "YOUR", "U", "DONE", "UH UH", "LIKE", "YOU ARE",
-                    "UH HUH", "UR", "NEXT", "WHAT?",
-                ],
-            ),
-            (
-                "YES",
-                vec![
-                    "OKAY", "RIGHT", "UHHH", "MIDDLE", "FIRST", "WHAT", "PRESS", "READY",
-                    "NOTHING", "YES",
-                ],
-            ),
-            (
-                "YOU ARE",
-                vec![
-                    "YOUR", "NEXT", "LIKE", "UH HUH", "WHAT?", "DONE", "UH UH", "HOLD", "YOU", "U",
-                    "YOU'RE", "SURE", "UR", "YOU ARE",
-                ],
-            ),
-            (
-                "YOU",
-                vec![
-                    "SURE", "YOU ARE", "YOUR", "YOU'RE", "NEXT", "UH HUH", "UR", "HOLD", "WHAT?",
-                    "YOU",
-                ],
-            ),
-            ("YOU'RE", vec!["YOU", "YOU'RE"]),
-            ("YOUR", vec!["UH UH", "YOU ARE", "UH HUH", "YOUR"]),
-            (
-                "READY",
-                vec![
-                    "YES", "OKAY", "WHAT", "MIDDLE", "LEFT", "PRESS", "RIGHT", "BLANK", "READY",
-                ],
-            )] {
-                whos_on_first_buttons.insert(whos_translate(label), v.iter().map(|word| whos_translate(word)).collect());
-            }
-        
-        egui_extras::install_image_loaders(ctx);
-
-        Self {
-            module: Module::Menu,
-            state: 0,
-            label: String::new(),
+        let kinds: Vec<_> = registry()
+            .into_iter()
+            .map(|create| {
+                let screen = create();
+                Kind {
+                    name: screen.name().to_owned(),
+                    category: screen.category(),
+                    needy: screen.needy(),
+                    create,
+                }
+            })
+            .collect();
+        // Until the bomb is set up, offer one of every module.
+        let modules = (0..kinds.len())
+            .map(|kind| Module::new(kind, &kinds))
+            .collect();
+        let mut application = Self {
+            kinds,
+            modules,
+            current: None,
+            setting_up: false,
+            editing_edgework: false,
+            showing_needy: false,
+            header: header::Header::default(),
+            bomb: Bomb::default(),
+            storage: storage.map(|storage| storage.join(STATE_FILE)),
+            saved: String::new(),
             painter: ctx.layer_painter(egui::LayerId::new(
                 egui::Order::Foreground,
                 egui::Id::new("overlay"),
             )),
-            keypad: HashMap::new(),
-            simon_says: SimonSays::default(),
-            whos_on_first_positions,
-            whos_on_first_buttons,
-            whos_on_first: [
-                String::new(),
-                String::new(),
-                String::new(),
-                String::new(),
-                String::new(),
-                String::new(),
-                String::new(),
-            ],
-            memory: Memory::default(),
-            wire_sequence: WireSequence::default(),
-            password: [
-                String::new(),
-                String::new(),
-                String::new(),
-                String::new(),
-                String::new(),
-            ],
+        };
+        application.load();
+        application
+    }
+
+    fn load(&mut self) {
+        let Some(path) = &self.storage else {
+            return;
+        };
+        let json = match std::fs::read_to_string(path) {
+            Ok(json) => json,
+            Err(err) => {
+                log::info!("No saved state at {path:?}: {err}");
+                return;
+            }
+        };
+        let saved: Saved = match serde_json::from_str(&json) {
+            Ok(saved) => saved,
+            Err(err) => {
+                log::warn!("Discarding saved state at {path:?}: {err}");
+                return;
+            }
+        };
+        if !saved.modules.is_empty() {
+            self.modules = saved
+                .modules
+                .into_iter()
+                .filter_map(|saved| {
+                    let kind = self.kinds.iter().position(|kind| kind.name == saved.kind)?;
+                    let mut module = Module::new(kind, &self.kinds);
+                    module.screen.load(saved.state);
+                    module.solved = saved.solved;
+                    module.strikes = saved.strikes;
+                    module.countdown = saved.countdown;
+                    Some(module)
+                })
+                .collect();
         }
+        self.current = saved.current.filter(|index| *index < self.modules.len());
+        self.bomb.facts = saved.edgework.facts();
+        self.bomb.edgework = saved.edgework;
+        self.bomb.timer = saved.timer;
+        self.bomb.set_strikes(saved.strikes);
+        self.saved = json;
     }
 
-    pub fn update(&mut self, ctx: &egui::Context) {
-        let mut style: egui::Style = (*ctx.style()).clone();
-        style.spacing.interact_size = Vec2::new(60.0, 30.0);
-        style.text_styles.insert(
-            egui::TextStyle::Name("uniform".into()),
-            egui::FontId::new(15.0, egui::FontFamily::Monospace),
-        );
-        style.override_text_style = Some(egui::TextStyle::Name("uniform".into()));
-        ctx.set_style(style);
+    /// Writes the state to storage if it changed since it was last written.
+    fn save(&mut self) {
+        let Some(path) = &self.storage else {
+            return;
+        };
+        let saved = Saved {
+            current: self.current,
+            edgework: self.bomb.edgework.clone(),
+            strikes: self.bomb.strikes,
+            timer: self.bomb.timer.clone(),
+            modules: self
+                .modules
+                .iter()
+                .map(|module| SavedModule {
+                    kind: self.kinds[module.kind].name.clone(),
+                    solved: module.solved,
+                    strikes: module.strikes,
+                    countdown: module.countdown,
+                    state: module.screen.save(),
+                })
+                .collect(),
+        };
+        let json = serde_json::to_string(&saved).expect("saved state is plain data");
+        if json == self.saved {
+            return;
+        }
+        // Write to a temporary file first so that being killed halfway leaves
+        // the previous state intact.
+        let temporary = path.with_extension("tmp");
+        let result = path
+            .parent()
+            .map_or(Ok(()), std::fs::create_dir_all)
+            .and_then(|()| std::fs::write(&temporary, &json))
+            .and_then(|()| std::fs::rename(&temporary, path));
+        match result {
+            Ok(()) => self.saved = json,
+            Err(err) => log::error!("Failed to save state to {path:?}: {err}"),
+        }
+    }
 
-        egui::CentralPanel::default().show(ctx, |ui| match self.module {
-            Module::Menu => {
-                let mut modules = Module::iter();
-                modules.next();
-                Grid::new("menu").num_columns(3).show(ui, |ui| {
-                    let mut i = 0;
-                    for module in modules {
-                        if ui.button(module.as_ref()).clicked() {
-                            self.module = module;
-                            self.state = 0;
-                        }
-                        if i % 3 == 2 {
-                            ui.end_row();
-                        }
-                        i += 1;
-                    }
-                });
-            },
-            Module::Wires => {
-                if ui.button("Menu").clicked() {
-                    self.module = Module::Menu;
-                    self.state = 0;
-                }
-                if self.state != 0 && ui.button("Reset").clicked() {
-                    self.state = 0;
-                }
-                match self.state {
-                    0 => {
-                        ui.label("Number of wires?");
-                        if ui.button("3").clicked() {
-                            self.state = 1;
-                        } else if ui.button("4").clicked() {
-                            self.state = 2;
-                        } else if ui.button("5").clicked() {
-                            self.state = 3;
-                        } else if ui.button("6").clicked() {
-                            self.state = 4;
-                        }
-                    }
-                    1 => {
-                        ui.label("3 Wires:\n\nCut the wire indicated by the first true condition.\n0 red: 2\n2+ blue: last blue\nelse: 3");
-                    }
-                    2 => {
-                        ui.label("4 Wires:\n\nCut the wire indicated by the first true condition.\n2+ red & serial number ends odd: last red\n0 red & last is yellow: 1\n1 blue: 1\n2+ yellow: 4\nelse: 2");
-                    }
-                    3 => {
-                        ui.label("5 Wires:\n\nCut the wire indicated by the first true condition.\nlast is black & serial number ends odd: 4\n0 black & 0 red: 2\nelse: 1");
-                    }
-                    4 => {
-                        ui.label("6 Wires\n\nCut the wire indicated by the first true condition.\n0 yellow & serial number ends odd: 3\n1 yellow & 2+ white: 4\n0 red: 6\nelse: 4");
-                    }
-                    s => panic!("Invalid state {s}.")
-                };
-            },
-            Module::Button => {
-                if ui.button("Menu").clicked() {
-                    self.module = Module::Menu;
-                }
-                ui.label("Take the action indicated by the first true condition.\nBlue \"abort\" button: hold\n\"detonate\" button & 2+ batteries: click\nwhite button & lit CAR indicator: hold\n3+ batteries & lit FRK indicator: click\nred \"hold\" button: click\nelse: hold\n\nHold Procedure:\nHold the button until the timer has a digit corresponding with the button light.\nblue: 4\nyellow: 5\nelse: 1");
-            },
-            Module::Keypad => {
-                if ui.button("Menu").clicked() {
-                    self.module = Module::Menu;
-                    self.keypad.clear();
-                    self.label.clear();
-                } else if ui.button("Reset").clicked() {
-                    self.keypad.clear();
-                    self.label.clear();
-                } else {
-                    ui.label("Select the icons below that are found on the module.");
-                    ui.label(if self.label.len() == 0 {
-                        "".to_owned()
-                    } else {
-                        format!("Click the buttons in this order:{}.", self.label)
-                    });
-                    let response = ui.image(egui::include_image!("Keypad.png")).interact(egui::Sense::click());
-                    if response.clicked() {
-                        if let Some(screen_position) = response.interact_pointer_pos() {
-                            let x = remap_clamp(screen_position.x, response.rect.min.x..=response.rect.max.x, 0.0..=4.999).floor();
-                            let y = remap_clamp(screen_position.y, response.rect.min.y..=response.rect.max.y, 0.0..=5.999).floor();
-                            let button = Self::KEYPAD_BUTTONS[y as usize][x as usize];
-                            if button != KeypadButton::None {
-                                if self.keypad.remove(&button).is_none() && self.keypad.len() < 4 {
-                                    self.keypad.insert(button, 0);
-                                }
+    /// Clears every module and the edgework to start on another bomb, keeping
+    /// the same modules to change in the setup.
+    fn new_bomb(&mut self) {
+        for module in &mut self.modules {
+            module.screen.reset();
+            module.solved = false;
+            module.strikes = 0;
+            module.countdown = needy::Countdown::default();
+        }
+        self.current = None;
+        self.setting_up = true;
+        self.bomb = Bomb::default();
+    }
 
-                                self.keypad.values_mut().for_each(|v| *v = 0);
-                                for column in 0..6 {
-                                    let mut i = 1;
-                                    for row in 0..7 {
-                                        let button = Self::KEYPAD_COLUMNS[column][row];
-                                        if self.keypad.contains_key(&button) {
-                                            self.keypad.insert(button, i);
-                                            i += 1;
-                                        }
-                                    }
-                                    if i > 4 {
-                                        let mut pairs = self.keypad.iter().collect::<Vec<_>>();
-                                        pairs.sort_by(|a, b| a.1.cmp(b.1));
-                                        self.label.clear();
-                                        pairs.iter().fold(&mut self.label, |a, b| {
-                                            a.push_str(" ");
-                                            a.push_str(b.0.name());
-                                            a
-                                        });
-                                        break;
-                                    } else {
-                                        self.keypad.values_mut().for_each(|v| *v = 0);
-                                        self.label.clear();
-                                    }
-                                }
-                            }
-                        }
-                    }
-                    for x in 0..5 {
-                        for y in 0..6 {
-                            if let Some(i) = self.keypad.get(&Self::KEYPAD_BUTTONS[y][x]) {
-                                let rect_x = lerp(response.rect.min.x..=response.rect.max.x, x as f32 / 5.0);
-                                let rect_y = lerp(response.rect.min.y..=response.rect.max.y, y as f32 / 6.0);
-                                self.painter.rect_stroke(
-                                    egui::Rect::from_min_size(Pos2::new(rect_x, rect_y), response.rect.size() / Vec2::new(5.0, 6.0)),
-                                    5.0,
-                                    egui::Stroke::new(10.0, if *i == 0 { Color32::RED } else { Color32::GREEN })
-                                );
-                                if *i > 0 {
-                                    self.painter.text(
-                                        Pos2::new(rect_x + 10.0, rect_y + 10.0),
-                                        egui::Align2::LEFT_TOP, i.to_string(),
-                                        egui::FontId::new(30.0, egui::FontFamily::Monospace), Color32::GREEN
-                                    );
-                                }
-                            }
-                        }
-                    }
-                }
-            },
-            Module::SimonSays => {
-                if ui.button("Menu").clicked() {
-                    self.module = Module::Menu;
-                    self.simon_says = SimonSays::default();
-                }
-                if ui.button("Reset").clicked() {
-                    self.simon_says.entered.clear();
-                }
-                ui.label("Input the bomb conditions and Simon Says flash pattern. Click the indicated button pattern.");
-                ui.checkbox(&mut self.simon_says.vowel, "Vowel");
-                ui.add(Slider::new(&mut self.simon_says.strikes, 0..=2).text("Strikes"));
-                Grid::new("simon says").show(ui, |ui| {
-                    if ui.add(Button::new("   ").fill(Color32::RED)).clicked() {
-                        self.simon_says.entered.push(SimonColor::Red);
-                    }
-                    if ui.add(Button::new("   ").fill(Color32::BLUE)).clicked() {
-                        self.simon_says.entered.push(SimonColor::Blue);
-                    }
-                    if ui.add(Button::new("   ").fill(Color32::GREEN)).clicked() {
-                        self.simon_says.entered.push(SimonColor::Green);
-                    }
-                    if ui.add(Button::new("   ").fill(Color32::YELLOW)).clicked() {
-                        self.simon_says.entered.push(SimonColor::Yellow);
-                    }
-                    ui.end_row();
+    /// The module's name, numbered when the bomb has more than one of its
+    /// kind.
+    fn label(&self, index: usize) -> String {
+        let module = &self.modules[index];
+        let name = &self.kinds[module.kind].name;
+        let same = |other: &Module| other.kind == module.kind;
+        if self.modules.iter().filter(|other| same(other)).count() > 1 {
+            let number = self.modules[..index]
+                .iter()
+                .filter(|other| same(other))
+                .count()
+                + 1;
+            format!("{name} {number}")
+        } else {
+            name.clone()
+        }
+    }
 
-                    ui.label("Flash");
-                    ui.label("Click");
-                    ui.end_row();
+    /// The indices into `modules` of the needy modules, with their kinds.
+    fn needy(&self) -> Vec<(usize, needy::NeedyKind)> {
+        self.modules
+            .iter()
+            .enumerate()
+            .filter_map(|(index, module)| Some((index, self.kinds[module.kind].needy?)))
+            .collect()
+    }
 
-                    for color in &self.simon_says.entered {
-                        ui.label(RichText::new("   ").background_color(color.color32()));
-                        ui.label(RichText::new("   ").background_color(self.simon_says.convert(color).color32()));
-                        ui.end_row();
-                    }
-                });
-            },
-            Module::WhosOnFirst => {
-                if ui.button("Menu").clicked() {
-                    self.module = Module::Menu;
-                    self.whos_on_first.iter_mut().for_each(|s| s.clear());
-                    self.state = 0;
-                    self.label.clear();
-                }
-                if ui.button("Reset").clicked() {
-                    self.whos_on_first.iter_mut().for_each(|s| s.clear());
-                    self.state = 0;
-                }
-                ui.label("Enter the first and last three letters of each word on the display and buttons.");
-                ui.label(if self.label.len() == 0 {
-                    "".to_owned()
-                } else {
-                    format!("Click the {} button.", &self.label)
-                });
-                
-                let mut changed = false;
-                let response = Frame::none().stroke(egui::Stroke { width: 10.0, color: if self.state == 0 {Color32::GOLD} else {Color32::TRANSPARENT}}).show(ui, |ui| {
-                    let color = if self.whos_on_first_positions.contains_key(&self.whos_on_first[0]) {Color32::GREEN} else {Color32::RED};
-                    ui.add(TextEdit::singleline(&mut self.whos_on_first[0]).text_color(color).desired_width(210.0))
-                });
-                ui.end_row();
-                if response.inner.changed() {
-                    changed = true;
-                    self.whos_on_first[0].make_ascii_uppercase();
-                }
-                if response.inner.clicked() {
-                    self.state = 0;
-                }
-                if response.inner.has_focus() {
-                    self.state = 0;
-                }
-                Grid::new("whos on first").num_columns(2).min_col_width(100.0).spacing((10.0, 10.0)).show(ui, |ui| {
-                    for i in 1..7 {
-                        let response = Frame::none().stroke(egui::Stroke { width: 10.0, color: if self.state == i {Color32::GOLD} else {Color32::TRANSPARENT}}).show(ui, |ui| {
-                        let color = if self.whos_on_first_buttons.contains_key(&self.whos_on_first[i]) {Color32::GREEN} else {Color32::RED};
-                        ui.add(TextEdit::singleline(&mut self.whos_on_first[i]).text_color(color).desired_width(100.0))
-                        });
-                        if response.inner.changed() {
-                            changed = true;
-                            self.whos_on_first[i].make_ascii_uppercase();
-                        }
-                        if response.inner.clicked() {
-                            self.state = i;
-                        }
-                        if response.inner.has_focus() {
-                            self.state = i;
-                        }
+    /// One line with the started countdowns, shown above every screen.
+    /// Returns whether one was clicked.
+    fn needy_bar(&self, ui: &mut egui::Ui) -> bool {
+        ui.horizontal(|ui| {
+            let mut clicked = false;
+            for (index, kind) in self.needy() {
+                clicked |= self.modules[index]
+                    .countdown
+                    .bar(ui, &self.label(index), kind);
+            }
+            clicked
+        })
+        .inner
+    }
 
-                        if i % 2 == 0 {
-                            ui.end_row();
-                        }
-                    }
-                });
-                
-                #[cfg(target_os = "android")]
-                {changed = keyboard(ui, &mut self.whos_on_first[self.state]);}
+    /// Countdowns for every needy module on the bomb.
+    fn dashboard(&mut self, ui: &mut egui::Ui) {
+        ui.label("Click \"Activated\" when the defuser reports a needy module starting, and \"Done\" once it is dealt with.");
+        let needy = self.needy();
+        if needy.is_empty() {
+            ui.label("The bomb has no needy modules. Add them in Setup.");
+        }
+        for (index, kind) in needy {
+            let label = self.label(index);
+            ui.separator();
+            self.modules[index].countdown.ui(ui, &label, kind);
+        }
+    }
 
-                if changed {
-                    let button = if let Some((_, position)) = self.whos_on_first_positions.iter().find(|(word, _)| **word == self.whos_on_first[0]) {
-                        let label = &self.whos_on_first[*position + 1];
-                        if let Some((_, buttons)) = self.whos_on_first_buttons.iter().find(|(word, _)| *word == label) {
-                            buttons.iter().find(|word| self.whos_on_first[1..7].contains(word))
-                        } else {
-                            None
-                        }
-                    } else {None};
-                    if let Some(button) = button {
-                        self.label = ["top left", "top right", "middle left", "middle right", "bottom left", "bottom right"][self.whos_on_first[1..7].iter().position(|s| s == button).unwrap()].to_string();
-                    } else {
-                        self.label.clear();
-                    }
-                }
-            },
-            Module::Memory => {
-                if ui.button("Menu").clicked() {
-                    self.module = Module::Menu;
+    fn setup(&mut self, ui: &mut egui::Ui) {
+        if ui.button("Done").clicked() {
+            self.setting_up = false;
+        }
+        ui.label("Set how many of each module the bomb has.");
+        Grid::new("setup").show(ui, |ui| {
+            for kind in 0..self.kinds.len() {
+                let count = self.modules.iter().filter(|m| m.kind == kind).count();
+                ui.label(&self.kinds[kind].name);
+                if ui.button("-").clicked() && count > 0 {
+                    let last = self.modules.iter().rposition(|m| m.kind == kind);
+                    self.modules.remove(last.expect("the count is not zero"));
                 }
-                if ui.button("Reset").clicked() {
-                    self.state = 0;
+                ui.label(count.to_string());
+                if ui.button("+").clicked() {
+                    // Keep the modules grouped by kind in menu order.
+                    let at = self.modules.partition_point(|m| m.kind <= kind);
+                    self.modules.insert(at, Module::new(kind, &self.kinds));
                 }
-                match self.state {
-                    0 => {
-                        self.memory = Memory::default();
-                        ui.label("Stage 1: Displayed:");
-                        if ui.button("1: click position 2").clicked() {
-                            self.memory.position1 = 2;
-                            self.state = 1;
-                        } else if ui.button("2: click position 2").clicked() {
-                            self.memory.position1 = 2;
-                            self.state = 1;
-                        } else if ui.button("3: click position 3").clicked() {
-                            self.memory.position1 = 3;
-                            self.state = 1;
-                        } else if ui.button("4: click position 4").clicked() {
-                            self.memory.position1 = 4;
-                            self.state = 1;
-                        }
-                    }
-                    1 => {
-                        ui.label("Label from stage 1:");
-                        for i in 1..=4 {
-                            if ui.button(i.to_string()).clicked() {
-                                self.memory.label1 = i;
-                                self.state = 2;
-                            }
-                        }
-                    }
-                    2 => {
-                        ui.label("Stage 2: Displayed:");
-                        if ui.button("1: click label 4").clicked() {
-                            self.memory.label2 = 4;
-                            self.state = 4;
-                        } else if ui.button(format!("2: click position {}", self.memory.position1)).clicked() {
-                            self.memory.position2 = self.memory.position1;
-                            self.state = 3;
-                        } else if ui.button("3: click position 1").clicked() {
-                            self.memory.position2 = 1;
-                            self.state = 3;
-                        } else if ui.button(format!("4: click position {}", self.memory.position1)).clicked() {
-                            self.memory.position2 = self.memory.position1;
-                            self.state = 3;
-                        }
-                    }
-                    3 => {
-                        ui.label("Label from stage 2:");
-                        for i in 1..=4 {
-                            if ui.button(i.to_string()).clicked() {
-                                self.memory.label2 = i;
-                                self.state = 5;
-                            }
-                        }
-                    }
-                    4 => {
-                        ui.label("Position from stage 2:");
-                        for i in 1..=4 {
-                            if ui.button(i.to_string()).clicked() {
-                                self.memory.position2 = i;
-                                self.state = 5;
-                            }
-                        }
-                    }
-                    5 => {
-                        ui.label("Stage 3: Displayed:");
-                        if ui.button(format!("1: click label {}", self.memory.label2)).clicked() {
-                            self.memory.label3 = self.memory.label2;
-                            self.state = 7;
-                        } else if ui.button(format!("2: click label {}", self.memory.label1)).clicked() {
-                            self.memory.label3 = self.memory.label1;
-                            self.state = 7;
-                        } else if ui.button("3: click position 3").clicked() {
-                            self.state = 6;
-                        } else if ui.button("4: click label 4").clicked() {
-                            self.memory.label3 = 4;
-                            self.state = 7;
-                        }
-                    }
-                    6 => {
-                        ui.label("Label from stage 3:");
-                        for i in 1..=4 {
-                            if ui.button(i.to_string()).clicked() {
-                                self.memory.label3 = i;
-                                self.state = 7;
-                            }
-                        }
+                ui.end_row();
+            }
+        });
+    }
+
+    fn menu(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            if ui.button("Edgework").clicked() {
+                self.editing_edgework = true;
+            }
+            if ui.button("Needy").clicked() {
+                self.showing_needy = true;
+            }
+            if ui.button("Setup").clicked() {
+                self.setting_up = true;
+            }
+            if ui.button("New bomb").clicked() {
+                self.new_bomb();
+            }
+        });
+        let remaining = self
+            .modules
+            .iter()
+            .filter(|m| self.kinds[m.kind].category == Category::Regular && !m.solved)
+            .count();
+        ui.label(format!("Remaining modules: {remaining}"));
+        for category in Category::ALL {
+            ui.label(category.name());
+            Grid::new(category.name()).num_columns(3).show(ui, |ui| {
+                let modules = (0..self.modules.len())
+                    .filter(|index| self.kinds[self.modules[*index].kind].category == category);
+                for (i, index) in modules.enumerate() {
+                    let module = &self.modules[index];
+                    let mut label = self.label(index);
+                    for _ in 0..module.strikes {
+                        label.push_str(" \u{2717}");
                     }
-                    7 => {
-                        ui.label("Stage 4: Displayed:");
-                        if ui.button(format!("1: click position {}", self.memory.position1)).clicked() {
-                            self.state = 8;
-                        } else if ui.button("2: click position 1").clicked() {
-                             self.state = 8;
-                        } else if ui.button(format!("3: click position {}", self.memory.position2)).clicked() {
-                            self.state = 8;
-                        } else if ui.button(format!("4: click position {}", self.memory.position2)).clicked() {
-                            self.state = 8;
-                        }
+                    let mut button = egui::Button::new(label);
+                    if module.solved {
+                        button = button.fill(Color32::DARK_GREEN);
+                    } else if module.screen.is_solved(&self.bomb) {
+                        button = button.stroke(egui::Stroke::new(2.0, Color32::DARK_GREEN));
                     }
-                    8 => {
-                        ui.label("Label from stage 4:");
-                        for i in 1..=4 {
-                            if ui.button(i.to_string()).clicked() {
-                                self.memory.label4 = i;
-                                self.state = 9;
-                            }
-                        }
+                    if ui.add(button).clicked() {
+                        self.current = Some(index);
                     }
-                    9 => {
-                        ui.label("Stage 5: Displayed:");
-                        let _ = ui.button(format!("1: click label {}", self.memory.label1));
-                        let _ = ui.button(format!("2: click label {}", self.memory.label2));
-                        let _ = ui.button(format!("3: click label {}", self.memory.label4));
-                        let _ = ui.button(format!("4: click label {}", self.memory.label3));
+                    if i % 3 == 2 {
+                        ui.end_row();
                     }
-                    s => panic!("Invalid state {s}.")
-                }
-                ui.label(RichText::new(format!(
-                    "\nMemory State:\nPosition Label\n======== =====\n{}        {}\n{}        {}\nX        {}\nX        {}\n",
-                    self.memory.position1, self.memory.label1, self.memory.position2,
-                    self.memory.label2, self.memory.label3, self.memory.label4
-                )));
-            },
-            Module::MorseCode => {
-                if ui.button("Menu").clicked() {
-                    self.module = Module::Menu;
                 }
-                ui.label("Set the frequency corresponding to the first Morse Code letters after the long pause. Then click the \"TX\" button.");
-                ui.image(egui::include_image!("MorseCode.png"));
-            },
-            Module::ComplicatedWires => {
-                if ui.button("Menu").clicked() {
-                    self.module = Module::Menu;
-                    self.state = 0;
-                }
-                if ui.button("Reset").clicked() {
-                    self.state = 0;
-                }
-                ui.label("For each wire, input its attributes.");
-                Grid::new("complicated wires").num_columns(4).show(ui, |ui| {
-                    let mut i = 0;
-                    for label in ["LED", "STAR", "BLUE", "RED"] {
-                        if ui.add(Button::new(RichText::new(label).color(Color32::BLACK)).fill(
-                            if self.state & (1 << i) == 0 { Color32::DARK_GRAY } else { Color32::GOLD }
-                        ).min_size(Vec2::new(40.0, 30.0))).clicked() {
-                            self.state ^= 1 << i;
-                        }
-                        i += 1;
-                    }
-                });
-                ui.label(format!("Cut when: {}", Self::COMPLICATED_WIRES[self.state]));
+            });
+        }
+    }
+
+    pub fn update(&mut self, ctx: &egui::Context) {
+        let mut style: egui::Style = (*ctx.style()).clone();
+        style.spacing.interact_size = egui::Vec2::new(60.0, 30.0);
+        style.text_styles.insert(
+            egui::TextStyle::Name("uniform".into()),
+            egui::FontId::new(15.0, egui::FontFamily::Monospace),
+        );
+        style.override_text_style = Some(egui::TextStyle::Name("uniform".into()));
+        ctx.set_style(style);
+
+        egui::TopBottomPanel::top("header").show(ctx, |ui| {
+            self.header.ui(ui, &mut self.bomb);
+            if self.modules.iter().any(|m| m.countdown.started()) && self.needy_bar(ui) {
+                self.showing_needy = true;
             }
-            Module::WireSequences => {
-                if ui.button("Menu").clicked() {
-                    self.module = Module::Menu;
-                    self.wire_sequence = WireSequence::default();
-                }
-                if ui.button("Reset").clicked() {
-                    self.wire_sequence = WireSequence::default();
-                }
-                ui.label("Until the module is solved:\n    For each wire 1-3:\n        Cut the wire if it is connected to the position indicated on the corresponding button below\n        Click the corresponding button below.\n    Click the down button.\n\nThe sliders below track how many wires of each color have been processed.");
-                if ui.button(format!("Red: {}", Self::WIRE_SEQUENCE[(self.wire_sequence.red) as usize])).clicked() && self.wire_sequence.red < 8 {
-                    self.wire_sequence.red += 1;
-                }
-                ui.add(Slider::new(&mut self.wire_sequence.red, 0..=8));
-                if ui.button(format!("Blue: {}", Self::WIRE_SEQUENCE[(self.wire_sequence.blue + 9) as usize])).clicked() && self.wire_sequence.blue < 8 {
-                    self.wire_sequence.blue += 1;
-                }
-                ui.add(Slider::new(&mut self.wire_sequence.blue, 0..=8));
-                if ui.button(format!("Black: {}", Self::WIRE_SEQUENCE[(self.wire_sequence.black + 18) as usize])).clicked() && self.wire_sequence.black < 8 {
-                    self.wire_sequence.black += 1;
-                }
-                ui.add(Slider::new(&mut self.wire_sequence.black, 0..=8));
-            },
-            Module::Mazes => {
-                if ui.button("Menu").clicked() {
-                    self.module = Module::Menu;
+        });
+        egui::CentralPanel::default().show(ctx, |ui| {
+            if self.editing_edgework {
+                if ui.button("Done").clicked() {
+                    self.editing_edgework = false;
                 }
-                ui.label("The maze layout is identified by two circular markings (the column(s) of these are enough). Using the arrow buttons, move the white square to the red triangle without crossing the lines shown on the maze layout.");
-                ui.image(egui::include_image!("Mazes.png"));
-            },
-            Module::Passwords => {
-                if ui.button("Menu").clicked() {
-                    self.module = Module::Menu;
-                    self.state = 0;
-                    self.label.clear();
-                    self.password.iter_mut().for_each(|f| f.clear());
+                if edgework::ui(ui, &mut self.bomb.edgework) {
+                    self.bomb.facts = self.bomb.edgework.facts();
                 }
-                if ui.button("Reset").clicked() {
-                    self.state = 0;
-                    self.label.clear();
-                    self.password.iter_mut().for_each(|f| f.clear());
+                return;
+            }
+            if self.showing_needy {
+                if ui.button("Done").clicked() {
+                    self.showing_needy = false;
                 }
-                ui.label("Enter all available letters for each position until only one word is shown. Enter that word into the bomb and click submit.");
-
-                let label_rect = ui.label(&self.label).rect;
-                ui.allocate_exact_size(Vec2::new(0.0, 90.0 - label_rect.height()), egui::Sense::hover());
-
-                let mut changed = false;
-                Grid::new("password").num_columns(2).min_col_width(0.0).spacing((10.0, 10.0)).show(ui, |ui| {
-                    for i in 0..5 {
-                        if i == self.state {
-                            ui.label(RichText::new((i + 1).to_string()).color(Color32::GOLD));
-                        } else {
-                            ui.label((i + 1).to_string());
+                self.dashboard(ui);
+                return;
+            }
+            if self.setting_up {
+                self.current = None;
+                self.setup(ui);
+                return;
+            }
+            match self.current {
+                None => self.menu(ui),
+                Some(index) => {
+                    let label = self.label(index);
+                    let module = &mut self.modules[index];
+                    ui.horizontal(|ui| {
+                        if ui.button("Menu").clicked() {
+                            self.current = None;
                         }
-
-                        let response = Frame::none().stroke(egui::Stroke { width: 10.0, color: if self.state == i {Color32::GOLD} else {Color32::TRANSPARENT}}).show(ui, |ui| {
-                            ui.add(TextEdit::singleline(&mut self.password[i]).desired_width(100.0))
-                        });
-                        if response.inner.changed() {
-                            changed = true;
-                            self.password[i].make_ascii_uppercase();
+                        if ui.button("Reset").clicked() {
+                            module.screen.reset();
                         }
-                        if response.inner.clicked() {
-                            self.state = i;
+                        if ui.button("Edgework").clicked() {
+                            self.editing_edgework = true;
                         }
-
-                        ui.end_row();
-                    }
-                });
-
-                #[cfg(target_os = "android")]
-                {keyboard(ui, &mut self.password[self.state]);}
-
-                if changed {
-                    self.label = Self::PASSWORDS.iter().filter(|word| {
-                        for (i, c) in word.chars().enumerate() {
-                            if self.password[i].len() > 0 && !self.password[i].contains(c) {
-                                return false;
-                            }
+                        if ui.button("Needy").clicked() {
+                            self.showing_needy = true;
                         }
-                        return true;
-                    }).fold(String::new(), |mut a, b| {
-                        a.push_str(b);
-                        a.push_str(" ");
-                        a
                     });
+                    ui.horizontal(|ui| {
+                        ui.label(RichText::new(label).strong());
+                        ui.checkbox(&mut module.solved, "Solved");
+                        if ui.button("Strike").clicked() {
+                            module.strikes = module.strikes.saturating_add(1);
+                            self.bomb.set_strikes(self.bomb.strikes + 1);
+                        }
+                        if module.strikes > 0 {
+                            ui.colored_label(
+                                Color32::RED,
+                                format!("{} strike(s) here", module.strikes),
+                            );
+                        }
+                    });
+                    module.screen.ui(ui, &self.bomb);
                 }
-            },
-            Module::Knobs => {
-                if ui.button("Menu").clicked() {
-                    self.module = Module::Menu;
-                }
-                ui.label("Rotate the knob to the position indicated by the lights. Knob positions are relative to the \"UP\" label on the bomb.");
-                ui.image(egui::include_image!("Knobs.png"));
             }
         });
+        self.save();
+        // Only redraw when the timer or a countdown shows another number.
+        if let Some(next) = self
+            .modules
+            .iter()
+            .filter_map(|module| module.countdown.next_change())
+            .chain(self.bomb.timer.next_change())
+            .min()
+        {
+            ctx.request_repaint_after(next);
+        }
         self.painter.text(
             ctx.screen_rect().center_bottom(),
             egui::Align2::CENTER_BOTTOM,
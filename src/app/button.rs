@@ -0,0 +1,158 @@
+use egui::{Color32, Grid, RichText};
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+
+use super::{Bomb, Category, ModuleScreen};
+use crate::solver::button::{self, Action, ButtonColor, ButtonLabel, StripColor};
+use crate::solver::timer;
+
+fn button_color32(color: ButtonColor) -> Color32 {
+    match color {
+        ButtonColor::Blue => Color32::BLUE,
+        ButtonColor::White => Color32::WHITE,
+        ButtonColor::Yellow => Color32::YELLOW,
+        ButtonColor::Red => Color32::RED,
+        ButtonColor::Other => Color32::DARK_GRAY,
+    }
+}
+
+fn strip_color32(color: StripColor) -> Color32 {
+    match color {
+        StripColor::Blue => Color32::BLUE,
+        StripColor::White => Color32::WHITE,
+        StripColor::Yellow => Color32::YELLOW,
+        StripColor::Red => Color32::RED,
+        StripColor::Other => Color32::DARK_GRAY,
+    }
+}
+
+/// A button showing a color's name on that color, highlighted when selected.
+fn color_button(ui: &mut egui::Ui, name: &str, fill: Color32, selected: bool) -> bool {
+    let text = RichText::new(name).color(if fill == Color32::DARK_GRAY || fill == Color32::BLUE {
+        Color32::WHITE
+    } else {
+        Color32::BLACK
+    });
+    ui.add(egui::Button::new(text).fill(fill).stroke(egui::Stroke::new(
+        if selected { 3.0 } else { 0.0 },
+        Color32::GOLD,
+    )))
+    .clicked()
+}
+
+#[derive(Default, Serialize, Deserialize)]
+pub struct Button {
+    color: Option<ButtonColor>,
+    label: Option<ButtonLabel>,
+    strip: Option<StripColor>,
+}
+
+impl Button {
+    fn action(&self, bomb: &Bomb) -> Option<Action> {
+        let facts = &bomb.facts;
+        Some(button::solve(
+            self.color?,
+            self.label?,
+            facts.batteries,
+            facts.lit_car,
+            facts.lit_frk,
+        ))
+    }
+}
+
+impl ModuleScreen for Button {
+    fn name(&self) -> &str {
+        "Button"
+    }
+
+    fn category(&self) -> Category {
+        Category::Regular
+    }
+
+    fn ui(&mut self, ui: &mut egui::Ui, bomb: &Bomb) {
+        let facts = &bomb.facts;
+        ui.label(format!(
+            "Batteries: {}, lit CAR: {}, lit FRK: {} (set in Edgework)",
+            facts.batteries,
+            if facts.lit_car { "yes" } else { "no" },
+            if facts.lit_frk { "yes" } else { "no" },
+        ));
+        Grid::new("button").show(ui, |ui| {
+            ui.label("Color");
+            for color in ButtonColor::iter() {
+                if color_button(
+                    ui,
+                    color.as_ref(),
+                    button_color32(color),
+                    self.color == Some(color),
+                ) {
+                    self.color = Some(color);
+                }
+            }
+            ui.end_row();
+            ui.label("Label");
+            for label in ButtonLabel::iter() {
+                if ui
+                    .selectable_label(self.label == Some(label), label.as_ref())
+                    .clicked()
+                {
+                    self.label = Some(label);
+                }
+            }
+            ui.end_row();
+        });
+
+        match self.action(bomb) {
+            None => {
+                ui.label("Enter the button's color and label.");
+            }
+            Some(Action::Press) => {
+                ui.label(RichText::new("Press and immediately release.").strong());
+            }
+            Some(Action::Hold) => {
+                ui.label(RichText::new("Hold the button. What color is the strip?").strong());
+                ui.horizontal(|ui| {
+                    for strip in StripColor::iter() {
+                        if color_button(
+                            ui,
+                            strip.as_ref(),
+                            strip_color32(strip),
+                            self.strip == Some(strip),
+                        ) {
+                            self.strip = Some(strip);
+                        }
+                    }
+                });
+                if let Some(strip) = self.strip {
+                    let digit = button::release_digit(strip);
+                    ui.label(
+                        RichText::new(format!(
+                            "Release when the timer has a {digit} in any position."
+                        ))
+                        .strong(),
+                    );
+                    if let Some(display) = bomb.timer.display() {
+                        let text = RichText::new(display.clone()).size(30.0).strong();
+                        ui.label(if timer::shows_digit(&display, digit) {
+                            text.color(Color32::GREEN)
+                        } else {
+                            text
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    fn is_solved(&self, bomb: &Bomb) -> bool {
+        match self.action(bomb) {
+            Some(Action::Press) => true,
+            Some(Action::Hold) => self.strip.is_some(),
+            None => false,
+        }
+    }
+}
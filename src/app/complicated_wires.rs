@@ -0,0 +1,119 @@
+use egui::{Button, Color32, Grid, RichText, Vec2};
+use serde::{Deserialize, Serialize};
+
+use super::{Bomb, Category, ModuleScreen};
+use crate::solver::complicated_wires::{self, ComplicatedWire, Condition};
+
+#[derive(Default, Serialize, Deserialize)]
+pub struct ComplicatedWires {
+    /// The wires on the panel from left to right.
+    wires: Vec<ComplicatedWire>,
+    /// Whether the serial number ends odd, when it is not in the edgework.
+    serial_odd: Option<bool>,
+}
+
+impl ComplicatedWires {
+    /// Whether to cut a wire, or `None` if that depends on an unknown serial.
+    fn verdict(&self, wire: ComplicatedWire, bomb: &Bomb) -> Option<bool> {
+        let facts = &bomb.facts;
+        let serial_odd = facts.serial_odd.or(self.serial_odd);
+        if serial_odd.is_none() && complicated_wires::condition(wire) == Condition::SerialEven {
+            return None;
+        }
+        Some(complicated_wires::solve(
+            wire,
+            serial_odd == Some(false),
+            facts.parallel_port,
+            facts.batteries,
+        ))
+    }
+}
+
+impl ModuleScreen for ComplicatedWires {
+    fn name(&self) -> &str {
+        "ComplicatedWires"
+    }
+
+    fn category(&self) -> Category {
+        Category::Regular
+    }
+
+    fn ui(&mut self, ui: &mut egui::Ui, bomb: &Bomb) {
+        let facts = &bomb.facts;
+        ui.label(format!(
+            "Batteries: {}, parallel port: {} (set in Edgework)",
+            facts.batteries,
+            if facts.parallel_port { "yes" } else { "no" },
+        ));
+        if facts.serial_odd.is_none() {
+            ui.horizontal(|ui| {
+                ui.label("Serial number ends");
+                for (odd, name) in [(false, "even"), (true, "odd")] {
+                    if ui
+                        .selectable_label(self.serial_odd == Some(odd), name)
+                        .clicked()
+                    {
+                        self.serial_odd = Some(odd);
+                    }
+                }
+            });
+        }
+
+        ui.label("For each wire from left to right, input its attributes.");
+        let verdicts: Vec<_> = self
+            .wires
+            .iter()
+            .map(|wire| self.verdict(*wire, bomb))
+            .collect();
+        Grid::new("complicated wires")
+            .num_columns(6)
+            .show(ui, |ui| {
+                for (i, wire) in self.wires.iter_mut().enumerate() {
+                    ui.label((i + 1).to_string());
+                    for (label, value) in [
+                        ("LED", &mut wire.led),
+                        ("STAR", &mut wire.star),
+                        ("BLUE", &mut wire.blue),
+                        ("RED", &mut wire.red),
+                    ] {
+                        let button = Button::new(RichText::new(label).color(Color32::BLACK))
+                            .fill(if *value {
+                                Color32::GOLD
+                            } else {
+                                Color32::DARK_GRAY
+                            })
+                            .min_size(Vec2::new(40.0, 30.0));
+                        if ui.add(button).clicked() {
+                            *value = !*value;
+                        }
+                    }
+                    ui.label(match verdicts[i] {
+                        Some(true) => RichText::new("CUT").strong().color(Color32::GREEN),
+                        Some(false) => RichText::new("DON'T CUT").strong().color(Color32::RED),
+                        None => RichText::new("?"),
+                    });
+                    ui.end_row();
+                }
+            });
+        ui.horizontal(|ui| {
+            if self.wires.len() < 6 && ui.button("Add wire").clicked() {
+                self.wires.push(ComplicatedWire::default());
+            }
+            if !self.wires.is_empty() && ui.button("Remove wire").clicked() {
+                self.wires.pop();
+            }
+        });
+    }
+
+    fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    fn is_solved(&self, bomb: &Bomb) -> bool {
+        !self.wires.is_empty()
+            && self
+                .wires
+                .iter()
+                .all(|wire| self.verdict(*wire, bomb).is_some())
+    }
+}
@@ -0,0 +1,104 @@
+use egui::{Button, Color32, Grid, RichText, Slider, TextEdit, Vec2};
+use strum::IntoEnumIterator;
+
+use crate::solver::edgework::{Edgework, Indicator, IndicatorLabel, Port};
+
+/// Draws the editor for the bomb's edgework. Returns whether anything changed.
+pub fn ui(ui: &mut egui::Ui, edgework: &mut Edgework) -> bool {
+    let mut changed = false;
+
+    ui.label("Serial number");
+    let color = if edgework.serial_valid() {
+        Color32::GREEN
+    } else {
+        Color32::RED
+    };
+    let serial_changed = ui
+        .add(
+            TextEdit::singleline(&mut edgework.serial)
+                .text_color(color)
+                .desired_width(100.0),
+        )
+        .changed();
+    #[cfg(target_os = "android")]
+    let serial_changed = serial_changed
+        | super::digits(ui, &mut edgework.serial)
+        | super::keyboard(ui, &mut edgework.serial);
+    if serial_changed {
+        edgework.serial.make_ascii_uppercase();
+        changed = true;
+    }
+
+    changed |= ui
+        .add(Slider::new(&mut edgework.batteries, 0..=12).text("Batteries"))
+        .changed();
+    changed |= ui
+        .add(Slider::new(&mut edgework.battery_holders, 0..=6).text("Battery holders"))
+        .changed();
+
+    ui.label("Indicators (tap to cycle off, unlit, lit)");
+    Grid::new("indicators").num_columns(4).show(ui, |ui| {
+        for (i, label) in IndicatorLabel::iter().enumerate() {
+            let index = edgework
+                .indicators
+                .iter()
+                .position(|indicator| indicator.label == label);
+            let lit = index.map(|index| edgework.indicators[index].lit);
+            let button = Button::new(RichText::new(label.as_ref()).color(match lit {
+                Some(true) => Color32::BLACK,
+                _ => Color32::WHITE,
+            }))
+            .fill(match lit {
+                None => Color32::DARK_GRAY,
+                Some(false) => Color32::BLACK,
+                Some(true) => Color32::GOLD,
+            })
+            .min_size(Vec2::new(50.0, 30.0));
+            if ui.add(button).clicked() {
+                match index {
+                    None => edgework.indicators.push(Indicator { label, lit: false }),
+                    Some(index) if !edgework.indicators[index].lit => {
+                        edgework.indicators[index].lit = true
+                    }
+                    Some(index) => {
+                        edgework.indicators.remove(index);
+                    }
+                }
+                changed = true;
+            }
+            if i % 4 == 3 {
+                ui.end_row();
+            }
+        }
+    });
+
+    ui.label("Ports");
+    Grid::new("ports").num_columns(3).show(ui, |ui| {
+        for (i, port) in Port::iter().enumerate() {
+            let present = edgework.has_port(port);
+            let button = Button::new(RichText::new(port.name()).color(if present {
+                Color32::BLACK
+            } else {
+                Color32::WHITE
+            }))
+            .fill(if present {
+                Color32::GOLD
+            } else {
+                Color32::DARK_GRAY
+            });
+            if ui.add(button).clicked() {
+                if present {
+                    edgework.ports.retain(|p| *p != port);
+                } else {
+                    edgework.ports.push(port);
+                }
+                changed = true;
+            }
+            if i % 3 == 2 {
+                ui.end_row();
+            }
+        }
+    });
+
+    changed
+}
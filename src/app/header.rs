@@ -0,0 +1,99 @@
+use std::time::{Duration, SystemTime};
+
+use egui::{DragValue, RichText};
+use serde::{Deserialize, Serialize};
+
+use super::Bomb;
+use crate::solver::timer;
+
+/// The most strikes a bomb can take without exploding.
+pub const MAX_STRIKES: u8 = 2;
+
+/// A copy of the bomb timer, running at the speed the strikes call for.
+/// It keeps running against the wall clock while the app is closed.
+#[derive(Default, Clone, Serialize, Deserialize)]
+pub struct Timer {
+    /// The time left when the timer was last synced or changed speed, and
+    /// when that was.
+    anchor: Option<(Duration, SystemTime)>,
+    /// How many times faster than real time the timer runs since `anchor`.
+    rate: f32,
+}
+
+impl Timer {
+    pub fn remaining(&self) -> Option<Duration> {
+        let (remaining, at) = self.anchor?;
+        let elapsed = at.elapsed().unwrap_or_default();
+        Some(remaining.saturating_sub(elapsed.mul_f32(self.rate)))
+    }
+
+    /// How long until the display changes, while the timer is running.
+    pub fn next_change(&self) -> Option<Duration> {
+        let remaining = self.remaining().filter(|remaining| !remaining.is_zero())?;
+        Some(timer::next_change(remaining).div_f32(self.rate))
+    }
+
+    /// What the bomb shows, if the timer has been synced.
+    pub fn display(&self) -> Option<String> {
+        self.remaining().map(timer::display)
+    }
+
+    fn sync(&mut self, remaining: Duration, strikes: u8) {
+        self.anchor = Some((remaining, SystemTime::now()));
+        self.rate = timer::rate(strikes);
+    }
+
+    /// Keeps the time left but runs at the speed for `strikes` from now on.
+    pub fn set_strikes(&mut self, strikes: u8) {
+        if let Some(remaining) = self.remaining() {
+            self.sync(remaining, strikes);
+        }
+    }
+}
+
+/// The time being typed in to sync the timer.
+#[derive(Default)]
+pub struct Header {
+    syncing: bool,
+    minutes: u64,
+    seconds: u64,
+}
+
+impl Header {
+    /// Draws the strike counter and the timer above every screen.
+    pub fn ui(&mut self, ui: &mut egui::Ui, bomb: &mut Bomb) {
+        ui.horizontal(|ui| {
+            ui.label("Strikes");
+            if ui.button("-").clicked() {
+                bomb.set_strikes(bomb.strikes.saturating_sub(1));
+            }
+            ui.label(RichText::new(bomb.strikes.to_string()).strong());
+            if ui.button("+").clicked() {
+                bomb.set_strikes(bomb.strikes + 1);
+            }
+            ui.separator();
+            ui.label("Timer");
+            ui.label(
+                RichText::new(bomb.timer.display().unwrap_or_else(|| "--:--".to_owned())).strong(),
+            );
+            if ui.button("Sync").clicked() {
+                self.syncing = !self.syncing;
+            }
+        });
+        if self.syncing {
+            ui.horizontal(|ui| {
+                ui.label("Time on the bomb");
+                ui.add(DragValue::new(&mut self.minutes).clamp_range(0..=99));
+                ui.label(":");
+                ui.add(DragValue::new(&mut self.seconds).clamp_range(0..=59));
+                if ui.button("Start").clicked() {
+                    bomb.timer.sync(
+                        Duration::from_secs(self.minutes * 60 + self.seconds),
+                        bomb.strikes,
+                    );
+                    self.syncing = false;
+                }
+            });
+        }
+    }
+}
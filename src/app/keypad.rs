@@ -0,0 +1,258 @@
+use std::collections::HashMap;
+
+use egui::{
+    lerp, remap_clamp, Button, Color32, FontFamily, FontId, Pos2, RichText, TextEdit, Vec2,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{Bomb, Category, ModuleScreen};
+use crate::solver::keypad::{self, KeypadButton};
+
+/// The font family holding the keypad symbol glyphs.
+const FONT: &str = "keypad";
+
+/// Adds the bundled keypad symbol font to `fonts`, both as its own family and
+/// as the last fallback of the default families so that symbols mixed into
+/// ordinary text, like the press order, are drawn too.
+pub fn add_font(fonts: &mut egui::FontDefinitions) {
+    fonts.font_data.insert(
+        FONT.to_owned(),
+        egui::FontData::from_static(include_bytes!("../KeypadSymbols.ttf")),
+    );
+    fonts
+        .families
+        .insert(FontFamily::Name(FONT.into()), vec![FONT.to_owned()]);
+    for family in [FontFamily::Proportional, FontFamily::Monospace] {
+        fonts
+            .families
+            .entry(family)
+            .or_default()
+            .push(FONT.to_owned());
+    }
+}
+
+fn glyph(button: KeypadButton, size: f32) -> RichText {
+    RichText::new(button.glyph()).font(FontId::new(size, FontFamily::Name(FONT.into())))
+}
+
+/// The symbols laid out on a grid.
+const BUTTONS: [[Option<KeypadButton>; 5]; 6] = [
+    [
+        Some(KeypadButton::O),
+        Some(KeypadButton::A),
+        Some(KeypadButton::Lambda),
+        Some(KeypadButton::N),
+        Some(KeypadButton::Person),
+    ],
+    [
+        Some(KeypadButton::H),
+        Some(KeypadButton::AntiC),
+        Some(KeypadButton::Euro),
+        Some(KeypadButton::Q),
+        Some(KeypadButton::EmptyStar),
+    ],
+    [
+        Some(KeypadButton::Question),
+        Some(KeypadButton::Copyright),
+        Some(KeypadButton::W),
+        Some(KeypadButton::X),
+        Some(KeypadButton::R),
+    ],
+    [
+        Some(KeypadButton::N6),
+        Some(KeypadButton::Paragraph),
+        Some(KeypadButton::B),
+        Some(KeypadButton::Smile),
+        Some(KeypadButton::Trident),
+    ],
+    [
+        Some(KeypadButton::C),
+        Some(KeypadButton::Snake),
+        Some(KeypadButton::FilledStar),
+        Some(KeypadButton::Puzzle),
+        Some(KeypadButton::AE),
+    ],
+    [
+        Some(KeypadButton::Yot),
+        Some(KeypadButton::Omega),
+        None,
+        None,
+        None,
+    ],
+];
+
+#[derive(Default, Serialize, Deserialize)]
+pub struct Keypad {
+    /// Selected symbols and their place in the press order, or 0 if unknown.
+    selected: HashMap<KeypadButton, u8>,
+    label: String,
+    /// Text typed to find a symbol by name or nickname.
+    search: String,
+}
+
+impl Keypad {
+    fn toggle(&mut self, button: KeypadButton) {
+        if self.selected.remove(&button).is_none() && self.selected.len() < 4 {
+            self.selected.insert(button, 0);
+        }
+
+        self.selected.values_mut().for_each(|v| *v = 0);
+        self.label.clear();
+        let buttons = self.selected.keys().copied().collect::<Vec<_>>();
+        if let Ok(order) = keypad::solve(&buttons) {
+            for (i, button) in order.iter().enumerate() {
+                self.selected.insert(*button, i as u8 + 1);
+                self.label
+                    .push_str(&format!(" {} ({})", button.glyph(), button.name()));
+            }
+        }
+    }
+}
+
+impl ModuleScreen for Keypad {
+    fn name(&self) -> &str {
+        "Keypad"
+    }
+
+    fn category(&self) -> Category {
+        Category::Regular
+    }
+
+    fn ui(&mut self, ui: &mut egui::Ui, _bomb: &Bomb) {
+        ui.label(
+            "Select the icons below that are found on the module, or search for them by name.",
+        );
+        ui.add(TextEdit::singleline(&mut self.search).hint_text("search, e.g. squid"));
+        #[cfg(target_os = "android")]
+        super::keyboard(ui, &mut self.search);
+        let results = keypad::search(&self.search);
+        if !self.search.trim().is_empty() && results.is_empty() {
+            ui.label("No symbol has that name.");
+        }
+        ui.horizontal_wrapped(|ui| {
+            for button in results {
+                if ui
+                    .add(Button::new(glyph(button, 30.0)).min_size(Vec2::splat(50.0)))
+                    .on_hover_text(button.name())
+                    .clicked()
+                {
+                    self.toggle(button);
+                    self.search.clear();
+                }
+            }
+        });
+        let picked: Vec<_> = self.selected.keys().copied().collect();
+        let hints = keypad::disambiguating(&picked);
+        if !self.label.is_empty() {
+            ui.label(format!("Click the buttons in this order:{}.", self.label));
+        } else if !picked.is_empty() {
+            let columns = keypad::possible_columns(&picked);
+            if columns.is_empty() {
+                ui.colored_label(Color32::RED, keypad::KeypadError::NoColumn.to_string());
+            } else {
+                let columns: Vec<_> = columns.iter().map(|i| (i + 1).to_string()).collect();
+                let hints: Vec<_> = hints.iter().map(|hint| hint.name()).collect();
+                ui.label(format!(
+                    "Possible columns: {}\nCheck for: {}",
+                    columns.join(", "),
+                    hints.join(", ")
+                ));
+            }
+        }
+        let width = ui.available_width().min(400.0);
+        let (response, painter) =
+            ui.allocate_painter(Vec2::new(width, width * 6.0 / 5.0), egui::Sense::click());
+        if response.clicked() {
+            if let Some(screen_position) = response.interact_pointer_pos() {
+                let x = remap_clamp(
+                    screen_position.x,
+                    response.rect.min.x..=response.rect.max.x,
+                    0.0..=4.999,
+                )
+                .floor();
+                let y = remap_clamp(
+                    screen_position.y,
+                    response.rect.min.y..=response.rect.max.y,
+                    0.0..=5.999,
+                )
+                .floor();
+                if let Some(button) = BUTTONS[y as usize][x as usize] {
+                    self.toggle(button);
+                }
+            }
+        }
+
+        let cell = response.rect.size() / Vec2::new(5.0, 6.0);
+        for (y, row) in BUTTONS.iter().enumerate() {
+            for (x, button) in row.iter().enumerate() {
+                if let Some(button) = button {
+                    let min = Pos2::new(
+                        lerp(response.rect.min.x..=response.rect.max.x, x as f32 / 5.0),
+                        lerp(response.rect.min.y..=response.rect.max.y, y as f32 / 6.0),
+                    );
+                    painter.rect_filled(
+                        egui::Rect::from_min_size(min, cell).shrink(2.0),
+                        5.0,
+                        Color32::WHITE,
+                    );
+                    painter.text(
+                        min + cell / 2.0,
+                        egui::Align2::CENTER_CENTER,
+                        button.glyph(),
+                        FontId::new(cell.y * 0.7, FontFamily::Name(FONT.into())),
+                        Color32::BLACK,
+                    );
+                }
+                if button.is_some_and(|button| hints.contains(&button)) {
+                    painter.rect_stroke(
+                        egui::Rect::from_min_size(
+                            Pos2::new(
+                                lerp(response.rect.min.x..=response.rect.max.x, x as f32 / 5.0),
+                                lerp(response.rect.min.y..=response.rect.max.y, y as f32 / 6.0),
+                            ),
+                            response.rect.size() / Vec2::new(5.0, 6.0),
+                        ),
+                        5.0,
+                        egui::Stroke::new(4.0, Color32::YELLOW),
+                    );
+                }
+                if let Some(i) = button.and_then(|button| self.selected.get(&button)) {
+                    let rect_x = lerp(response.rect.min.x..=response.rect.max.x, x as f32 / 5.0);
+                    let rect_y = lerp(response.rect.min.y..=response.rect.max.y, y as f32 / 6.0);
+                    painter.rect_stroke(
+                        egui::Rect::from_min_size(
+                            Pos2::new(rect_x, rect_y),
+                            response.rect.size() / Vec2::new(5.0, 6.0),
+                        ),
+                        5.0,
+                        egui::Stroke::new(
+                            10.0,
+                            if *i == 0 {
+                                Color32::RED
+                            } else {
+                                Color32::GREEN
+                            },
+                        ),
+                    );
+                    if *i > 0 {
+                        painter.text(
+                            Pos2::new(rect_x + 10.0, rect_y + 10.0),
+                            egui::Align2::LEFT_TOP,
+                            i.to_string(),
+                            egui::FontId::new(30.0, egui::FontFamily::Monospace),
+                            Color32::GREEN,
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    fn is_solved(&self, _bomb: &Bomb) -> bool {
+        !self.label.is_empty()
+    }
+}
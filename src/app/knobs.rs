@@ -0,0 +1,71 @@
+use egui::{Color32, RichText, Sense, Stroke, Vec2};
+use serde::{Deserialize, Serialize};
+
+use super::needy::NeedyKind;
+use super::{Bomb, Category, ModuleScreen};
+use crate::solver::knobs::{self, Leds};
+
+#[derive(Default, Serialize, Deserialize)]
+pub struct Knobs {
+    leds: Leds,
+}
+
+impl ModuleScreen for Knobs {
+    fn name(&self) -> &str {
+        "Knobs"
+    }
+
+    fn category(&self) -> Category {
+        Category::Needy
+    }
+
+    fn needy(&self) -> Option<NeedyKind> {
+        Some(NeedyKind::Knobs)
+    }
+
+    fn ui(&mut self, ui: &mut egui::Ui, _bomb: &Bomb) {
+        ui.label("Tap the lit LEDs. Knob positions are relative to the \"UP\" label on the bomb.");
+        for row in self.leds.iter_mut() {
+            ui.horizontal(|ui| {
+                for led in row.iter_mut() {
+                    let (response, painter) =
+                        ui.allocate_painter(Vec2::splat(40.0), Sense::click());
+                    if response.clicked() {
+                        *led = !*led;
+                    }
+                    painter.circle(
+                        response.rect.center(),
+                        15.0,
+                        if *led {
+                            Color32::GREEN
+                        } else {
+                            Color32::from_gray(30)
+                        },
+                        Stroke::new(2.0, Color32::GRAY),
+                    );
+                }
+            });
+        }
+
+        if self.leds == Leds::default() {
+            return;
+        }
+        match knobs::solve(&self.leds) {
+            Ok(position) => ui.label(
+                RichText::new(position.as_ref().to_uppercase())
+                    .size(30.0)
+                    .strong()
+                    .color(Color32::GREEN),
+            ),
+            Err(error) => ui.colored_label(Color32::RED, format!("Check the LEDs again: {error}.")),
+        };
+    }
+
+    fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    fn is_solved(&self, _bomb: &Bomb) -> bool {
+        knobs::solve(&self.leds).is_ok()
+    }
+}
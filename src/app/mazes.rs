@@ -0,0 +1,160 @@
+use egui::{Color32, Pos2, Rect, Sense, Shape, Stroke, Vec2};
+use serde::{Deserialize, Serialize};
+
+use super::{Bomb, Category, ModuleScreen};
+use crate::solver::mazes::{self, Cell, Direction, MAZES};
+
+#[derive(Default, Serialize, Deserialize)]
+pub struct Mazes {
+    /// Index into `MAZES` once a marker has been tapped.
+    maze: Option<usize>,
+    start: Option<Cell>,
+    goal: Option<Cell>,
+    error: Option<String>,
+}
+
+impl Mazes {
+    fn path(&self) -> Option<Vec<Direction>> {
+        mazes::solve(&MAZES[self.maze?], self.start?, self.goal?).ok()
+    }
+
+    fn tap(&mut self, cell: Cell) {
+        self.error = None;
+        match (self.maze, self.start, self.goal) {
+            (None, _, _) => match mazes::identify(cell) {
+                Ok(maze) => self.maze = Some(maze),
+                Err(error) => self.error = Some(error.to_string()),
+            },
+            (Some(_), Some(_), None) => self.goal = Some(cell),
+            // Tapping again starts over from where the white square is now.
+            (Some(_), _, _) => {
+                self.start = Some(cell);
+                self.goal = None;
+            }
+        }
+    }
+}
+
+impl ModuleScreen for Mazes {
+    fn name(&self) -> &str {
+        "Mazes"
+    }
+
+    fn category(&self) -> Category {
+        Category::Regular
+    }
+
+    fn ui(&mut self, ui: &mut egui::Ui, _bomb: &Bomb) {
+        ui.label(match (self.maze, self.start, self.goal) {
+            (None, _, _) => "Tap a cell with a circle marker.",
+            (Some(_), None, _) => "Tap the white square.",
+            (Some(_), Some(_), None) => "Tap the red triangle.",
+            _ => "Follow the path. Tap a cell to start again from there.",
+        });
+        if let Some(error) = &self.error {
+            ui.colored_label(Color32::RED, error);
+        }
+
+        let side = ui.available_width().min(360.0);
+        let (response, painter) = ui.allocate_painter(Vec2::splat(side), Sense::click());
+        let rect = response.rect;
+        let size = side / 6.0;
+        let center = |cell: Cell| {
+            rect.min + Vec2::new(cell.column as f32 + 0.5, cell.row as f32 + 0.5) * size
+        };
+
+        if response.clicked() {
+            if let Some(position) = response.interact_pointer_pos() {
+                let offset = (position - rect.min) / size;
+                self.tap(Cell {
+                    column: (offset.x as u8).min(5),
+                    row: (offset.y as u8).min(5),
+                });
+            }
+        }
+
+        painter.rect_filled(rect, 0.0, Color32::from_gray(30));
+        let thin = Stroke::new(1.0, Color32::DARK_GRAY);
+        let wall = Stroke::new(4.0, Color32::LIGHT_GRAY);
+        for row in 0..6 {
+            for column in 0..6 {
+                let cell = Cell { column, row };
+                let center = center(cell);
+                painter.circle_filled(center, 3.0, Color32::GRAY);
+                let Some(maze) = self.maze.map(|maze| &MAZES[maze]) else {
+                    painter.rect_stroke(
+                        Rect::from_center_size(center, Vec2::splat(size)),
+                        0.0,
+                        thin,
+                    );
+                    continue;
+                };
+                let min = center - Vec2::splat(size / 2.0);
+                let max = center + Vec2::splat(size / 2.0);
+                for (direction, from, to) in [
+                    (Direction::Up, min, Pos2::new(max.x, min.y)),
+                    (Direction::Down, Pos2::new(min.x, max.y), max),
+                    (Direction::Left, min, Pos2::new(min.x, max.y)),
+                    (Direction::Right, Pos2::new(max.x, min.y), max),
+                ] {
+                    if !maze.can_move(cell, direction) {
+                        painter.line_segment([from, to], wall);
+                    }
+                }
+                if maze.markers.contains(&cell) {
+                    painter.circle_stroke(center, size / 3.0, Stroke::new(3.0, Color32::GREEN));
+                }
+            }
+        }
+
+        if let (Some(start), Some(path)) = (self.start, self.path()) {
+            let mut cell = start;
+            let mut points = vec![center(cell)];
+            for direction in &path {
+                cell = cell.step(*direction).unwrap();
+                points.push(center(cell));
+            }
+            painter.add(Shape::line(points, Stroke::new(4.0, Color32::LIGHT_BLUE)));
+        }
+        if let Some(start) = self.start {
+            painter.rect_filled(
+                Rect::from_center_size(center(start), Vec2::splat(size / 3.0)),
+                0.0,
+                Color32::WHITE,
+            );
+        }
+        if let Some(goal) = self.goal {
+            let center = center(goal);
+            let r = size / 4.0;
+            painter.add(Shape::convex_polygon(
+                vec![
+                    center + Vec2::new(0.0, -r),
+                    center + Vec2::new(r, r),
+                    center + Vec2::new(-r, r),
+                ],
+                Color32::RED,
+                Stroke::NONE,
+            ));
+        }
+
+        if let Some(path) = self.path() {
+            let directions: Vec<_> = path
+                .iter()
+                .map(|direction| direction.as_ref().to_lowercase())
+                .collect();
+            ui.label(if directions.is_empty() {
+                "You are already there.".to_owned()
+            } else {
+                directions.join(", ")
+            });
+        }
+    }
+
+    fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    fn is_solved(&self, _bomb: &Bomb) -> bool {
+        self.path().is_some()
+    }
+}
@@ -0,0 +1,315 @@
+use egui::{Grid, RichText};
+use serde::{Deserialize, Serialize};
+
+use super::{ordinal, Bomb, Category, ModuleScreen};
+use crate::solver::memory::{self, Press, Stage};
+
+/// A completed stage along with the display that decided it, so the stage can
+/// be worked out again when an earlier one is corrected.
+#[derive(Clone, Copy, Serialize, Deserialize)]
+struct Entry {
+    display: u8,
+    stage: Stage,
+}
+
+/// What the screen is waiting for in the current stage.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+enum Step {
+    /// The number on the display.
+    #[default]
+    Display,
+    /// The button was chosen by position; waiting for its label.
+    Label { display: u8, position: u8 },
+    /// The button was chosen by label; waiting for its position.
+    Position { display: u8, label: u8 },
+}
+
+#[derive(Default, Serialize, Deserialize)]
+pub struct Memory {
+    entries: Vec<Entry>,
+    step: Step,
+    /// The row of the table being corrected.
+    editing: Option<usize>,
+    /// Whether the display and all four labels are entered at once.
+    direct: bool,
+    /// The display entered in direct mode, or 0.
+    display: u8,
+    /// The labels entered in direct mode from left to right, or 0.
+    labels: [u8; 4],
+}
+
+impl Memory {
+    fn history(&self) -> Vec<Stage> {
+        self.entries.iter().map(|entry| entry.stage).collect()
+    }
+
+    /// Moves on from the display of the current stage, either asking for the
+    /// other half of the button or recording the stage straight away.
+    fn enter_display(&mut self, display: u8) {
+        let Ok(press) = memory::press(&self.history(), display) else {
+            return;
+        };
+        match press {
+            Press::Position(position) => self.step = Step::Label { display, position },
+            // Positions after stage 2 are never needed.
+            Press::Label(label) if self.entries.len() >= 2 => {
+                self.entries.push(Entry {
+                    display,
+                    stage: Stage {
+                        position: None,
+                        label: Some(label),
+                    },
+                });
+                self.step = Step::Display;
+            }
+            Press::Label(label) => self.step = Step::Position { display, label },
+        }
+    }
+
+    /// Rewinds one step.
+    fn back(&mut self) {
+        match self.step {
+            Step::Label { .. } | Step::Position { .. } => self.step = Step::Display,
+            Step::Display if self.direct => {
+                if let Some(entry) = self.entries.pop() {
+                    self.display = entry.display;
+                    self.labels = [0; 4];
+                }
+            }
+            Step::Display => {
+                let Some(entry) = self.entries.pop() else {
+                    return;
+                };
+                self.enter_display(entry.display);
+                // The stage was recorded without a question, so undo it fully.
+                if self.step == Step::Display {
+                    self.entries.pop();
+                }
+            }
+        }
+        self.editing = None;
+    }
+
+    /// Works out every stage from `from` onwards again after an earlier stage
+    /// was corrected. Stops at the first stage whose pressed button changed,
+    /// since the defuser has to report the other half of the new button.
+    fn recompute(&mut self, from: usize) {
+        for i in from..self.entries.len() {
+            let Entry { display, stage } = self.entries[i];
+            let press = memory::press(&self.history()[..i], display);
+            let step = match press {
+                Ok(Press::Position(position)) if Some(position) != stage.position => {
+                    Step::Label { display, position }
+                }
+                Ok(Press::Label(label)) if Some(label) != stage.label && i >= 2 => {
+                    self.entries[i].stage = Stage {
+                        position: None,
+                        label: Some(label),
+                    };
+                    continue;
+                }
+                Ok(Press::Label(label)) if Some(label) != stage.label => {
+                    Step::Position { display, label }
+                }
+                Ok(_) => continue,
+                Err(_) => Step::Display,
+            };
+            self.entries.truncate(i);
+            self.step = step;
+            self.editing = self.editing.filter(|row| *row < i);
+            return;
+        }
+        // The current question may refer to a different button now.
+        if let Step::Label { display, .. } | Step::Position { display, .. } = self.step {
+            self.step = Step::Display;
+            self.enter_display(display);
+        }
+    }
+
+    /// Takes the display and the four labels and answers with one button.
+    fn direct_input(&mut self, ui: &mut egui::Ui, stage: usize) {
+        ui.label(format!("Stage {stage}:"));
+        Grid::new("memory direct").show(ui, |ui| {
+            ui.label("Display");
+            for v in 1..=4 {
+                if ui
+                    .selectable_label(self.display == v, v.to_string())
+                    .clicked()
+                {
+                    self.display = v;
+                }
+            }
+            ui.end_row();
+            for i in 0..4 {
+                ui.label(format!("{} label", ordinal(i + 1)));
+                for v in 1..=4 {
+                    if ui
+                        .selectable_label(self.labels[i] == v, v.to_string())
+                        .clicked()
+                    {
+                        self.labels[i] = v;
+                    }
+                }
+                ui.end_row();
+            }
+        });
+        if self.display == 0 || self.labels.contains(&0) {
+            return;
+        }
+        match memory::solve(&self.history(), self.display, self.labels) {
+            Ok(pressed) => {
+                ui.label(
+                    RichText::new(format!(
+                        "Press the {} button, labeled {}.",
+                        ordinal(pressed.position as usize),
+                        pressed.label
+                    ))
+                    .strong(),
+                );
+                if stage < 5 && ui.button("Pressed").clicked() {
+                    self.entries.push(Entry {
+                        display: self.display,
+                        stage: pressed.into(),
+                    });
+                    self.display = 0;
+                    self.labels = [0; 4];
+                }
+            }
+            Err(error) => {
+                ui.colored_label(egui::Color32::RED, error.to_string());
+            }
+        }
+    }
+
+    fn edit(&mut self, ui: &mut egui::Ui, row: usize) {
+        let stage = &mut self.entries[row].stage;
+        let mut changed = false;
+        ui.label(format!("Correct stage {}:", row + 1));
+        Grid::new("memory edit").show(ui, |ui| {
+            for (name, value) in [
+                ("Position", &mut stage.position),
+                ("Label", &mut stage.label),
+            ] {
+                ui.label(name);
+                for v in 1..=4 {
+                    if ui
+                        .selectable_label(*value == Some(v), v.to_string())
+                        .clicked()
+                        && *value != Some(v)
+                    {
+                        *value = Some(v);
+                        changed = true;
+                    }
+                }
+                ui.end_row();
+            }
+        });
+        if ui.button("Done").clicked() {
+            self.editing = None;
+        }
+        if changed {
+            self.recompute(row + 1);
+        }
+    }
+}
+
+impl ModuleScreen for Memory {
+    fn name(&self) -> &str {
+        "Memory"
+    }
+
+    fn category(&self) -> Category {
+        Category::Regular
+    }
+
+    fn ui(&mut self, ui: &mut egui::Ui, _bomb: &Bomb) {
+        let stage = self.entries.len() + 1;
+        ui.horizontal(|ui| {
+            if ui.button("Back").clicked() {
+                self.back();
+            }
+            ui.checkbox(&mut self.direct, "Direct input");
+        });
+        match self.step {
+            Step::Display if self.direct => self.direct_input(ui, stage),
+            Step::Display => {
+                ui.label(format!("Stage {stage}: Displayed:"));
+                for display in 1..=4 {
+                    let Ok(press) = memory::press(&self.history(), display) else {
+                        continue;
+                    };
+                    let text = match press {
+                        Press::Position(position) => {
+                            format!("{display}: click position {position}")
+                        }
+                        Press::Label(label) => format!("{display}: click label {label}"),
+                    };
+                    if ui.button(text).clicked() && stage < 5 {
+                        self.enter_display(display);
+                    }
+                }
+            }
+            Step::Label { display, position } => {
+                ui.label(format!("Label from stage {stage}:"));
+                for label in 1..=4 {
+                    if ui.button(label.to_string()).clicked() {
+                        self.entries.push(Entry {
+                            display,
+                            stage: Stage {
+                                position: Some(position),
+                                label: Some(label),
+                            },
+                        });
+                        self.step = Step::Display;
+                    }
+                }
+            }
+            Step::Position { display, label } => {
+                ui.label(format!("Position from stage {stage}:"));
+                for position in 1..=4 {
+                    if ui.button(position.to_string()).clicked() {
+                        self.entries.push(Entry {
+                            display,
+                            stage: Stage {
+                                position: Some(position),
+                                label: Some(label),
+                            },
+                        });
+                        self.step = Step::Display;
+                    }
+                }
+            }
+        }
+
+        ui.label(RichText::new("\nMemory State (tap a stage to correct it):"));
+        let value = |v: Option<u8>| v.map_or("X".to_owned(), |v| v.to_string());
+        Grid::new("memory state").striped(true).show(ui, |ui| {
+            ui.label("Stage");
+            ui.label("Position");
+            ui.label("Label");
+            ui.end_row();
+            for i in 0..4 {
+                let recorded = self.entries.get(i).map(|entry| entry.stage);
+                let stage = recorded.unwrap_or_default();
+                let response = ui.selectable_label(self.editing == Some(i), (i + 1).to_string());
+                ui.label(value(stage.position));
+                ui.label(value(stage.label));
+                ui.end_row();
+                if response.clicked() && recorded.is_some() {
+                    self.editing = Some(i);
+                }
+            }
+        });
+        if let Some(row) = self.editing {
+            self.edit(ui, row);
+        }
+    }
+
+    fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    fn is_solved(&self, _bomb: &Bomb) -> bool {
+        self.entries.len() == 4 && self.step == Step::Display
+    }
+}
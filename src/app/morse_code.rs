@@ -0,0 +1,118 @@
+use egui::{Button, Color32, Grid, RichText, Vec2};
+use serde::{Deserialize, Serialize};
+
+use super::{Bomb, Category, ModuleScreen};
+use crate::solver::morse_code::{self, Word};
+
+#[derive(Default, Serialize, Deserialize)]
+pub struct MorseCode {
+    /// The letters decoded so far.
+    letters: String,
+    /// The dots and dashes of the letter being received.
+    sequence: String,
+    /// Whether the letters were received starting right after the long pause.
+    after_pause: bool,
+    error: Option<String>,
+}
+
+impl MorseCode {
+    fn candidates(&self) -> Vec<Word> {
+        if self.after_pause {
+            morse_code::solve(&self.letters)
+        } else {
+            morse_code::solve_looped(&self.letters)
+        }
+    }
+
+    fn end_letter(&mut self) {
+        if self.sequence.is_empty() {
+            return;
+        }
+        match morse_code::decode(&self.sequence) {
+            Ok(letter) => {
+                self.letters.push(letter);
+                self.error = None;
+            }
+            Err(error) => self.error = Some(error.to_string()),
+        }
+        self.sequence.clear();
+    }
+}
+
+impl ModuleScreen for MorseCode {
+    fn name(&self) -> &str {
+        "MorseCode"
+    }
+
+    fn category(&self) -> Category {
+        Category::Regular
+    }
+
+    fn ui(&mut self, ui: &mut egui::Ui, _bomb: &Bomb) {
+        ui.label("Enter the flashes, ending each letter with a gap. Set the frequency shown and click the \"TX\" button.");
+        ui.checkbox(&mut self.after_pause, "Started after the long pause");
+        ui.horizontal(|ui| {
+            let size = Vec2::new(50.0, 40.0);
+            if ui.add(Button::new("\u{2022}").min_size(size)).clicked() {
+                self.sequence.push('.');
+            }
+            if ui.add(Button::new("\u{2014}").min_size(size)).clicked() {
+                self.sequence.push('-');
+            }
+            if ui.add(Button::new("Gap").min_size(size)).clicked() {
+                self.end_letter();
+            }
+            if ui.add(Button::new("\u{2190}").min_size(size)).clicked()
+                && self.sequence.pop().is_none()
+            {
+                self.letters.pop();
+            }
+        });
+
+        ui.label(
+            RichText::new(format!("{} {}", self.letters, self.sequence))
+                .size(25.0)
+                .strong(),
+        );
+        if let Some(error) = &self.error {
+            ui.colored_label(Color32::RED, error);
+        }
+
+        let candidates = self.candidates();
+        if candidates.is_empty() {
+            ui.colored_label(Color32::RED, "No word matches these letters.");
+        }
+        Grid::new("morse code").striped(true).show(ui, |ui| {
+            ui.label("Word");
+            ui.label("Frequency");
+            ui.label("Right presses");
+            ui.end_row();
+            for word in &candidates {
+                let text = |text: String| {
+                    let text = RichText::new(text);
+                    if candidates.len() == 1 {
+                        text.strong().color(Color32::GREEN)
+                    } else {
+                        text
+                    }
+                };
+                ui.label(text(word.word.to_owned()));
+                ui.label(text(format!(
+                    "{}.{:03} MHz",
+                    word.frequency / 1000,
+                    word.frequency % 1000
+                )));
+                ui.label(text(word.presses().to_string()));
+                ui.end_row();
+            }
+        });
+    }
+
+    fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    fn is_solved(&self, _bomb: &Bomb) -> bool {
+        self.candidates().len() == 1
+    }
+}
@@ -0,0 +1,172 @@
+use std::time::{Duration, SystemTime};
+
+use egui::{Color32, RichText};
+use serde::{Deserialize, Serialize};
+
+use super::{Bomb, Category, ModuleScreen};
+
+/// The needy modules the dashboard keeps a countdown for.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum NeedyKind {
+    Knobs,
+    VentingGas,
+    CapacitorDischarge,
+}
+
+impl NeedyKind {
+    /// How long the module gives the defuser once it activates.
+    fn time(&self) -> Duration {
+        Duration::from_secs(match self {
+            NeedyKind::Knobs => 80,
+            NeedyKind::VentingGas => 40,
+            NeedyKind::CapacitorDischarge => 45,
+        })
+    }
+
+    fn reference(&self) -> &'static str {
+        match self {
+            NeedyKind::Knobs => "Read out the lit LEDs and open the Knobs screen for the position to turn the knob to.",
+            NeedyKind::VentingGas => "\"VENT GAS?\": press Y. \"DETONATE?\": press N.",
+            NeedyKind::CapacitorDischarge => "Hold the lever down to discharge the capacitor before it fills up.",
+        }
+    }
+}
+
+/// Green while more than half the time is left, then yellow, then red.
+fn urgency(remaining: Duration, total: Duration) -> Color32 {
+    let fraction = remaining.as_secs_f32() / total.as_secs_f32();
+    if fraction > 0.5 {
+        Color32::GREEN
+    } else if fraction > 0.25 {
+        Color32::YELLOW
+    } else {
+        Color32::RED
+    }
+}
+
+/// The countdown of one needy module on the bomb. It keeps running against
+/// the wall clock while the app is closed.
+#[derive(Default, Clone, Copy, Serialize, Deserialize)]
+pub struct Countdown {
+    /// When the module runs out of time, once it has activated.
+    deadline: Option<SystemTime>,
+}
+
+impl Countdown {
+    fn remaining(&self) -> Option<Duration> {
+        self.deadline.map(|deadline| {
+            deadline
+                .duration_since(SystemTime::now())
+                .unwrap_or_default()
+        })
+    }
+
+    /// Whether the module activated and has not been dealt with, including
+    /// after its time ran out.
+    pub fn started(&self) -> bool {
+        self.deadline.is_some()
+    }
+
+    /// How long until the shown number of seconds changes, while the
+    /// countdown is running.
+    pub fn next_change(&self) -> Option<Duration> {
+        let remaining = self.remaining().filter(|remaining| !remaining.is_zero())?;
+        Some(match remaining.subsec_nanos() {
+            0 => Duration::from_secs(1),
+            nanos => Duration::from_nanos(nanos.into()),
+        })
+    }
+
+    fn text(&self, kind: NeedyKind) -> Option<RichText> {
+        let remaining = self.remaining()?;
+        Some(if remaining.is_zero() {
+            RichText::new("Expired").strong().color(Color32::RED)
+        } else {
+            RichText::new(format!("{}s", remaining.as_secs_f32().ceil()))
+                .strong()
+                .color(urgency(remaining, kind.time()))
+        })
+    }
+
+    /// The module's entry in the bar above every screen, if it is started.
+    /// Returns whether it was clicked.
+    pub fn bar(&self, ui: &mut egui::Ui, label: &str, kind: NeedyKind) -> bool {
+        let Some(text) = self.text(kind) else {
+            return false;
+        };
+        let clicked = ui.link(label).clicked();
+        ui.label(text);
+        clicked
+    }
+
+    /// The module's entry on the dashboard.
+    pub fn ui(&mut self, ui: &mut egui::Ui, label: &str, kind: NeedyKind) {
+        ui.horizontal(|ui| {
+            ui.label(RichText::new(label).strong());
+            if let Some(text) = self.text(kind) {
+                ui.label(text);
+            }
+            if ui.button("Activated").clicked() {
+                self.deadline = Some(SystemTime::now() + kind.time());
+            }
+            if ui.button("Done").clicked() {
+                self.deadline = None;
+            }
+        });
+        ui.label(kind.reference());
+    }
+}
+
+#[derive(Default, Serialize, Deserialize)]
+pub struct VentingGas;
+
+impl ModuleScreen for VentingGas {
+    fn name(&self) -> &str {
+        "VentingGas"
+    }
+
+    fn category(&self) -> Category {
+        Category::Needy
+    }
+
+    fn needy(&self) -> Option<NeedyKind> {
+        Some(NeedyKind::VentingGas)
+    }
+
+    fn ui(&mut self, ui: &mut egui::Ui, _bomb: &Bomb) {
+        ui.label(NeedyKind::VentingGas.reference());
+    }
+
+    fn reset(&mut self) {}
+
+    fn is_solved(&self, _bomb: &Bomb) -> bool {
+        false
+    }
+}
+
+#[derive(Default, Serialize, Deserialize)]
+pub struct CapacitorDischarge;
+
+impl ModuleScreen for CapacitorDischarge {
+    fn name(&self) -> &str {
+        "CapacitorDischarge"
+    }
+
+    fn category(&self) -> Category {
+        Category::Needy
+    }
+
+    fn needy(&self) -> Option<NeedyKind> {
+        Some(NeedyKind::CapacitorDischarge)
+    }
+
+    fn ui(&mut self, ui: &mut egui::Ui, _bomb: &Bomb) {
+        ui.label(NeedyKind::CapacitorDischarge.reference());
+    }
+
+    fn reset(&mut self) {}
+
+    fn is_solved(&self, _bomb: &Bomb) -> bool {
+        false
+    }
+}
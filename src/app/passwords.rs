@@ -0,0 +1,105 @@
+use egui::{Color32, Frame, Grid, RichText, TextEdit, Vec2};
+use serde::{Deserialize, Serialize};
+
+use super::{Bomb, Category, ModuleScreen};
+use crate::solver::passwords;
+
+#[derive(Default, Serialize, Deserialize)]
+pub struct Passwords {
+    /// The letters entered for each of the five columns.
+    columns: [String; 5],
+    /// Index into `columns` of the field being typed into.
+    selected: usize,
+    label: String,
+}
+
+impl ModuleScreen for Passwords {
+    fn name(&self) -> &str {
+        "Passwords"
+    }
+
+    fn category(&self) -> Category {
+        Category::Regular
+    }
+
+    fn ui(&mut self, ui: &mut egui::Ui, _bomb: &Bomb) {
+        ui.label("Enter all available letters for each position until only one word is shown. Enter that word into the bomb and click submit.");
+
+        let label_rect = ui.label(&self.label).rect;
+        ui.allocate_exact_size(
+            Vec2::new(0.0, 90.0 - label_rect.height()),
+            egui::Sense::hover(),
+        );
+
+        let mut changed = false;
+        let expected = passwords::expected_remaining(&self.columns);
+        let best = passwords::best_column(&self.columns);
+        Grid::new("password")
+            .num_columns(3)
+            .min_col_width(0.0)
+            .spacing((10.0, 10.0))
+            .show(ui, |ui| {
+                for i in 0..5 {
+                    if i == self.selected {
+                        ui.label(RichText::new((i + 1).to_string()).color(Color32::GOLD));
+                    } else {
+                        ui.label((i + 1).to_string());
+                    }
+
+                    let response = Frame::none()
+                        .stroke(egui::Stroke {
+                            width: 10.0,
+                            color: if self.selected == i {
+                                Color32::GOLD
+                            } else {
+                                Color32::TRANSPARENT
+                            },
+                        })
+                        .show(ui, |ui| {
+                            ui.add(TextEdit::singleline(&mut self.columns[i]).desired_width(100.0))
+                        });
+                    if response.inner.changed() {
+                        changed = true;
+                        self.columns[i].make_ascii_uppercase();
+                    }
+                    if response.inner.clicked() {
+                        self.selected = i;
+                    }
+
+                    match expected[i] {
+                        Some(expected) if best == Some(i) => ui.label(
+                            RichText::new(format!("ask next (~{expected:.1} left)"))
+                                .strong()
+                                .color(Color32::GREEN),
+                        ),
+                        Some(expected) => ui.label(format!("~{expected:.1} left")),
+                        None => ui.label(""),
+                    };
+                    ui.end_row();
+                }
+            });
+
+        #[cfg(target_os = "android")]
+        {
+            changed |= super::keyboard(ui, &mut self.columns[self.selected]);
+        }
+
+        if changed {
+            self.label = passwords::solve(&self.columns)
+                .iter()
+                .fold(String::new(), |mut a, b| {
+                    a.push_str(b);
+                    a.push(' ');
+                    a
+                });
+        }
+    }
+
+    fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    fn is_solved(&self, _bomb: &Bomb) -> bool {
+        passwords::solve(&self.columns).len() == 1
+    }
+}
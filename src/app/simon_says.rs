@@ -0,0 +1,120 @@
+use egui::{Button, Color32, Grid, RichText};
+use serde::{Deserialize, Serialize};
+
+use super::{Bomb, Category, ModuleScreen};
+use crate::solver::simon_says::{self, SimonColor};
+
+fn color32(color: SimonColor) -> Color32 {
+    match color {
+        SimonColor::Red => Color32::RED,
+        SimonColor::Blue => Color32::BLUE,
+        SimonColor::Green => Color32::GREEN,
+        SimonColor::Yellow => Color32::YELLOW,
+    }
+}
+
+/// A flashed color and the number of strikes when it was entered.
+#[derive(Clone, Copy, Serialize, Deserialize)]
+struct Flash {
+    color: SimonColor,
+    strikes: u8,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+pub struct SimonSays {
+    flashes: Vec<Flash>,
+}
+
+fn swatch(color: SimonColor) -> RichText {
+    RichText::new("   ").background_color(color32(color))
+}
+
+/// The swatch of the button to click, or the error in red if the strikes
+/// are out of the manual's range, as they can be in a hand-edited save.
+fn convert(color: SimonColor, vowel: bool, strikes: u8) -> RichText {
+    match simon_says::translate(color, vowel, strikes) {
+        Ok(color) => swatch(color),
+        Err(err) => RichText::new(err.to_string()).color(Color32::RED),
+    }
+}
+
+impl ModuleScreen for SimonSays {
+    fn name(&self) -> &str {
+        "SimonSays"
+    }
+
+    fn category(&self) -> Category {
+        Category::Regular
+    }
+
+    fn ui(&mut self, ui: &mut egui::Ui, bomb: &Bomb) {
+        ui.label("Input the bomb conditions and Simon Says flash pattern. Click the indicated button pattern.");
+        let vowel = bomb.facts.serial_vowel;
+        ui.label(match vowel {
+            Some(true) => "The serial number contains a vowel.",
+            Some(false) => "The serial number does not contain a vowel.",
+            None => "Enter the serial number in Edgework.",
+        });
+        ui.label(format!("Strikes: {} (set in the header)", bomb.strikes));
+        ui.horizontal(|ui| {
+            for color in [
+                SimonColor::Red,
+                SimonColor::Blue,
+                SimonColor::Green,
+                SimonColor::Yellow,
+            ] {
+                if ui.add(Button::new("   ").fill(color32(color))).clicked() {
+                    self.flashes.push(Flash {
+                        color,
+                        strikes: bomb.strikes,
+                    });
+                }
+            }
+            if ui.button("Undo").clicked() {
+                self.flashes.pop();
+            }
+        });
+
+        let Some(vowel) = vowel else {
+            return;
+        };
+        if self
+            .flashes
+            .iter()
+            .any(|flash| flash.strikes != bomb.strikes)
+        {
+            ui.label(
+                RichText::new("Strikes changed \u{2014} new full sequence is:")
+                    .strong()
+                    .color(Color32::GOLD),
+            );
+            ui.horizontal(|ui| {
+                for flash in &self.flashes {
+                    ui.label(convert(flash.color, vowel, bomb.strikes));
+                }
+            });
+        }
+
+        Grid::new("simon says").show(ui, |ui| {
+            ui.label("Flash");
+            ui.label("Strikes");
+            ui.label("Click");
+            ui.end_row();
+
+            for flash in &self.flashes {
+                ui.label(swatch(flash.color));
+                ui.label(flash.strikes.to_string());
+                ui.label(convert(flash.color, vowel, flash.strikes));
+                ui.end_row();
+            }
+        });
+    }
+
+    fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    fn is_solved(&self, _bomb: &Bomb) -> bool {
+        false
+    }
+}
@@ -0,0 +1,276 @@
+use egui::{Button, Color32, Frame, Grid, RichText, TextEdit};
+use serde::{Deserialize, Serialize};
+
+use super::{Bomb, Category, ModuleScreen};
+use crate::solver::whos_on_first::{self, Spelling};
+
+/// Splits spellings into the words that sound like nothing else followed by
+/// one group per family of words that sound alike.
+fn families(spellings: Vec<Spelling>) -> Vec<Vec<Spelling>> {
+    let mut groups: Vec<Vec<Spelling>> = vec![vec![]];
+    let mut families: Vec<u8> = vec![];
+    for spelling in spellings {
+        match spelling.family {
+            None => groups[0].push(spelling),
+            Some(family) => match families.iter().position(|f| *f == family) {
+                Some(i) => groups[i + 1].push(spelling),
+                None => {
+                    families.push(family);
+                    groups.push(vec![spelling]);
+                }
+            },
+        }
+    }
+    groups
+}
+
+fn display_families() -> Vec<Vec<Spelling>> {
+    families(whos_on_first::display_spellings())
+}
+
+fn button_families() -> Vec<Vec<Spelling>> {
+    families(whos_on_first::button_spellings())
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct WhosOnFirst {
+    /// The display word followed by the six button words, either in full or
+    /// abbreviated.
+    words: [String; 7],
+    /// Index into `words` of the field being typed into.
+    selected: usize,
+    label: String,
+    /// Whether words are typed rather than picked from a list.
+    typing: bool,
+    /// The words and answers of the stages already done.
+    completed: Vec<([String; 7], String)>,
+    #[serde(skip, default = "display_families")]
+    display_families: Vec<Vec<Spelling>>,
+    #[serde(skip, default = "button_families")]
+    button_families: Vec<Vec<Spelling>>,
+}
+
+impl Default for WhosOnFirst {
+    fn default() -> Self {
+        Self {
+            words: Default::default(),
+            selected: 0,
+            label: String::new(),
+            typing: false,
+            completed: vec![],
+            display_families: display_families(),
+            button_families: button_families(),
+        }
+    }
+}
+
+impl WhosOnFirst {
+    /// Whether a field holds a known word, in full or abbreviated.
+    fn valid(&self, field: usize) -> bool {
+        let abbreviation = whos_on_first::abbreviate(&self.words[field]);
+        if field == 0 {
+            whos_on_first::display_from_abbreviation(&abbreviation).is_some()
+        } else {
+            whos_on_first::button_from_abbreviation(&abbreviation).is_some()
+        }
+    }
+
+    fn next_stage(&mut self) {
+        let words = std::mem::take(&mut self.words);
+        self.completed
+            .push((words, std::mem::take(&mut self.label)));
+        self.selected = 0;
+    }
+
+    fn solve(&mut self) {
+        self.label.clear();
+        let display =
+            whos_on_first::display_from_abbreviation(&whos_on_first::abbreviate(&self.words[0]));
+        let buttons = self.words[1..7]
+            .iter()
+            .map(|word| whos_on_first::button_from_abbreviation(&whos_on_first::abbreviate(word)))
+            .collect::<Option<Vec<_>>>()
+            .and_then(|buttons| <[&str; 6]>::try_from(buttons).ok());
+        if let (Some(display), Some(buttons)) = (display, buttons) {
+            if let Ok(position) = whos_on_first::solve(display, &buttons) {
+                self.label = position.name().to_owned();
+            }
+        }
+    }
+}
+
+impl ModuleScreen for WhosOnFirst {
+    fn name(&self) -> &str {
+        "WhosOnFirst"
+    }
+
+    fn category(&self) -> Category {
+        Category::Regular
+    }
+
+    fn ui(&mut self, ui: &mut egui::Ui, _bomb: &Bomb) {
+        ui.label("Pick or type each word on the display and buttons. Typing the first and last three letters is enough.");
+        ui.checkbox(&mut self.typing, "Type words");
+        for (i, (words, label)) in self.completed.iter().enumerate() {
+            ui.label(format!(
+                "Stage {}: \"{}\" -> {label} ({})",
+                i + 1,
+                words[0],
+                words[1..].join(", ")
+            ));
+        }
+        if self.completed.len() == 3 {
+            ui.label("All three stages are done.");
+            return;
+        }
+        ui.horizontal(|ui| {
+            ui.label(format!("Stage {}/3", self.completed.len() + 1));
+            if !self.label.is_empty() {
+                ui.label(format!("Click the {} button.", &self.label));
+                if ui.button("Next stage").clicked() {
+                    self.next_stage();
+                }
+            }
+        });
+
+        let mut changed = false;
+        let response = Frame::none()
+            .stroke(egui::Stroke {
+                width: 10.0,
+                color: if self.selected == 0 {
+                    Color32::GOLD
+                } else {
+                    Color32::TRANSPARENT
+                },
+            })
+            .show(ui, |ui| {
+                let color = if self.valid(0) {
+                    Color32::GREEN
+                } else {
+                    Color32::RED
+                };
+                ui.add(
+                    TextEdit::singleline(&mut self.words[0])
+                        .text_color(color)
+                        .desired_width(210.0),
+                )
+            });
+        ui.end_row();
+        if response.inner.changed() {
+            changed = true;
+            self.words[0].make_ascii_uppercase();
+        }
+        if response.inner.clicked() || response.inner.has_focus() {
+            self.selected = 0;
+        }
+        Grid::new("whos on first")
+            .num_columns(2)
+            .min_col_width(100.0)
+            .spacing((10.0, 10.0))
+            .show(ui, |ui| {
+                for i in 1..7 {
+                    let response = Frame::none()
+                        .stroke(egui::Stroke {
+                            width: 10.0,
+                            color: if self.selected == i {
+                                Color32::GOLD
+                            } else {
+                                Color32::TRANSPARENT
+                            },
+                        })
+                        .show(ui, |ui| {
+                            let color = if self.valid(i) {
+                                Color32::GREEN
+                            } else {
+                                Color32::RED
+                            };
+                            ui.add(
+                                TextEdit::singleline(&mut self.words[i])
+                                    .text_color(color)
+                                    .desired_width(100.0),
+                            )
+                        });
+                    if response.inner.changed() {
+                        changed = true;
+                        self.words[i].make_ascii_uppercase();
+                    }
+                    if response.inner.clicked() || response.inner.has_focus() {
+                        self.selected = i;
+                    }
+
+                    if i % 2 == 0 {
+                        ui.end_row();
+                    }
+                }
+            });
+
+        let typed = &self.words[self.selected];
+        if !typed.is_empty() && !self.valid(self.selected) {
+            let suggestions = if self.selected == 0 {
+                whos_on_first::suggest_display(typed)
+            } else {
+                whos_on_first::suggest_button(typed)
+            };
+            ui.horizontal_wrapped(|ui| {
+                ui.label(if suggestions.is_empty() {
+                    "No similar words."
+                } else {
+                    "Did you mean:"
+                });
+                for word in suggestions {
+                    if ui.button(word).clicked() {
+                        self.words[self.selected] = word.to_owned();
+                        changed = true;
+                    }
+                }
+            });
+        }
+
+        if !self.typing {
+            let families = if self.selected == 0 {
+                &self.display_families
+            } else {
+                &self.button_families
+            };
+            let mut picked = None;
+            for family in families {
+                ui.separator();
+                ui.horizontal_wrapped(|ui| {
+                    for spelling in family {
+                        let text = if spelling.spoken == spelling.word {
+                            RichText::new(spelling.word)
+                        } else if spelling.word.is_empty() {
+                            RichText::new(spelling.spoken).italics()
+                        } else {
+                            RichText::new(format!("{} ({})", spelling.word, spelling.spoken))
+                        };
+                        if ui.add(Button::new(text)).clicked() {
+                            picked = Some(spelling.word);
+                        }
+                    }
+                });
+            }
+            if let Some(word) = picked {
+                self.words[self.selected] = word.to_owned();
+                self.selected = (self.selected + 1) % self.words.len();
+                changed = true;
+            }
+        }
+        #[cfg(target_os = "android")]
+        if self.typing {
+            changed |= super::keyboard(ui, &mut self.words[self.selected]);
+        }
+
+        if changed {
+            self.solve();
+        }
+    }
+
+    fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    fn is_solved(&self, _bomb: &Bomb) -> bool {
+        self.completed.len() == 3
+    }
+}
@@ -0,0 +1,152 @@
+use egui::{Color32, Grid, RichText};
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+
+use super::{Bomb, Category, ModuleScreen};
+use crate::solver::wire_sequences::{self, Panel, SequenceColor, Terminal, Wire};
+
+/// The number of panels on the module.
+const PANELS: usize = 4;
+
+#[derive(Default, Serialize, Deserialize)]
+pub struct WireSequences {
+    /// The panels already done.
+    panels: Vec<Panel>,
+    /// The color and terminal picked for each wire of the current panel.
+    current: [(Option<SequenceColor>, Option<Terminal>); 3],
+}
+
+fn color32(color: SequenceColor) -> Color32 {
+    match color {
+        SequenceColor::Red => Color32::RED,
+        SequenceColor::Blue => Color32::LIGHT_BLUE,
+        SequenceColor::Black => Color32::GRAY,
+    }
+}
+
+fn describe(wire: &Option<Wire>, cut: Option<bool>) -> String {
+    match (wire, cut) {
+        (Some(wire), Some(cut)) => format!(
+            "{} {} {}",
+            wire.color.as_ref(),
+            wire.terminal.as_ref(),
+            if cut { "cut" } else { "keep" }
+        ),
+        _ => "-".to_owned(),
+    }
+}
+
+impl WireSequences {
+    /// The current panel, if every wire present has a terminal.
+    fn panel(&self) -> Option<Panel> {
+        let mut panel = [None; 3];
+        for (wire, (color, terminal)) in panel.iter_mut().zip(self.current) {
+            if let Some(color) = color {
+                *wire = Some(Wire {
+                    color,
+                    terminal: terminal?,
+                });
+            }
+        }
+        Some(panel)
+    }
+
+    fn undo(&mut self) {
+        if let Some(panel) = self.panels.pop() {
+            self.current = panel.map(|wire| match wire {
+                Some(wire) => (Some(wire.color), Some(wire.terminal)),
+                None => (None, None),
+            });
+        }
+    }
+}
+
+impl ModuleScreen for WireSequences {
+    fn name(&self) -> &str {
+        "WireSequences"
+    }
+
+    fn category(&self) -> Category {
+        Category::Regular
+    }
+
+    fn ui(&mut self, ui: &mut egui::Ui, _bomb: &Bomb) {
+        ui.label("Pick the color and letter of each wire on the panel, cut the wires marked CUT, then click \"Next panel\" and press the down button.");
+        ui.horizontal(|ui| {
+            if ui.button("Undo last panel").clicked() {
+                self.undo();
+            }
+        });
+
+        for (i, panel) in self.panels.iter().enumerate() {
+            let cuts = wire_sequences::solve_panel(&self.panels[..=i]).unwrap_or_default();
+            let wires: Vec<_> = panel
+                .iter()
+                .zip(cuts)
+                .map(|(wire, cut)| describe(wire, cut))
+                .collect();
+            ui.label(format!("Panel {}: {}", i + 1, wires.join(", ")));
+        }
+        if self.panels.len() == PANELS {
+            ui.label("All panels are done.");
+            return;
+        }
+
+        let panel = self.panel();
+        let cuts = panel.map(|panel| {
+            let mut panels = self.panels.clone();
+            panels.push(panel);
+            wire_sequences::solve_panel(&panels)
+        });
+        ui.label(format!("Panel {}/{PANELS}", self.panels.len() + 1));
+        Grid::new("wire sequences")
+            .spacing((10.0, 10.0))
+            .show(ui, |ui| {
+                for (i, (color, terminal)) in self.current.iter_mut().enumerate() {
+                    ui.label((i + 1).to_string());
+                    ui.horizontal(|ui| {
+                        ui.selectable_value(color, None, "none");
+                        for option in SequenceColor::iter() {
+                            ui.selectable_value(
+                                color,
+                                Some(option),
+                                RichText::new(option.as_ref()).color(color32(option)),
+                            );
+                        }
+                    });
+                    ui.horizontal(|ui| {
+                        for option in Terminal::iter() {
+                            ui.selectable_value(terminal, Some(option), option.as_ref());
+                        }
+                    });
+                    match (&cuts, *color) {
+                        (_, None) => ui.label(""),
+                        (Some(Ok(cuts)), Some(_)) => match cuts[i] {
+                            Some(true) => ui.colored_label(Color32::GREEN, "CUT"),
+                            _ => ui.colored_label(Color32::RED, "DON'T CUT"),
+                        },
+                        (Some(Err(error)), Some(_)) => {
+                            ui.colored_label(Color32::RED, error.to_string())
+                        }
+                        (None, Some(_)) => ui.label("?"),
+                    };
+                    ui.end_row();
+                }
+            });
+
+        if let Some(panel) = panel {
+            if ui.button("Next panel").clicked() {
+                self.panels.push(panel);
+                self.current = Default::default();
+            }
+        }
+    }
+
+    fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    fn is_solved(&self, _bomb: &Bomb) -> bool {
+        self.panels.len() == PANELS
+    }
+}
@@ -0,0 +1,143 @@
+use egui::{Button, Color32, Grid, RichText, Stroke, Vec2};
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+
+use super::{ordinal, Bomb, Category, ModuleScreen};
+use crate::solver::wires::{self, Solution, WireColor};
+
+fn color32(color: WireColor) -> Color32 {
+    match color {
+        WireColor::Red => Color32::RED,
+        WireColor::Blue => Color32::BLUE,
+        WireColor::Yellow => Color32::YELLOW,
+        WireColor::White => Color32::WHITE,
+        WireColor::Black => Color32::BLACK,
+    }
+}
+
+#[derive(Default, Serialize, Deserialize)]
+pub struct Wires {
+    /// The six wire slots from top to bottom; empty slots have no wire.
+    slots: [Option<WireColor>; 6],
+    /// The slot the next picked color goes into.
+    selected: usize,
+    /// Whether the serial number ends odd, when it is not in the edgework.
+    serial_odd: Option<bool>,
+}
+
+impl Wires {
+    fn wires(&self) -> Vec<WireColor> {
+        self.slots.iter().flatten().copied().collect()
+    }
+
+    /// The solution, if the serial number is known or does not matter.
+    fn solution(&self, serial_odd: Option<bool>) -> Option<Solution> {
+        let wires = self.wires();
+        match serial_odd {
+            Some(odd) => wires::solve(&wires, odd).ok(),
+            None => {
+                let odd = wires::solve(&wires, true).ok()?;
+                (wires::solve(&wires, false).ok()? == odd).then_some(odd)
+            }
+        }
+    }
+}
+
+impl ModuleScreen for Wires {
+    fn name(&self) -> &str {
+        "Wires"
+    }
+
+    fn category(&self) -> Category {
+        Category::Regular
+    }
+
+    fn ui(&mut self, ui: &mut egui::Ui, bomb: &Bomb) {
+        ui.label("Pick a slot, then the color of its wire from top to bottom.");
+        Grid::new("wire slots").show(ui, |ui| {
+            for (i, slot) in self.slots.iter().enumerate() {
+                let button = match slot {
+                    Some(color) => Button::new("").fill(color32(*color)),
+                    None => Button::new("empty"),
+                }
+                .min_size(Vec2::new(40.0, 30.0))
+                .stroke(if self.selected == i {
+                    Stroke::new(3.0, Color32::GOLD)
+                } else {
+                    Stroke::new(1.0, Color32::GRAY)
+                });
+                if ui.add(button).clicked() {
+                    self.selected = i;
+                }
+            }
+            ui.end_row();
+            for color in WireColor::iter().map(Some).chain([None]) {
+                let button = match color {
+                    Some(color) => Button::new(RichText::new(color.as_ref()).color(
+                        if color == WireColor::Black {
+                            Color32::WHITE
+                        } else {
+                            Color32::BLACK
+                        },
+                    ))
+                    .fill(color32(color)),
+                    None => Button::new("empty"),
+                }
+                .min_size(Vec2::new(40.0, 30.0));
+                if ui.add(button).clicked() {
+                    self.slots[self.selected] = color;
+                    self.selected = (self.selected + 1).min(self.slots.len() - 1);
+                }
+            }
+        });
+
+        let count = self.wires().len();
+        let Ok(rules) = wires::rules(count) else {
+            ui.label(format!("{count} wires entered; the module has 3 to 6."));
+            return;
+        };
+
+        let serial_odd = bomb.facts.serial_odd.or(self.serial_odd);
+        let solution = self.solution(serial_odd);
+        if bomb.facts.serial_odd.is_none() {
+            ui.horizontal(|ui| {
+                ui.label("Serial number ends");
+                for (odd, name) in [(false, "even"), (true, "odd")] {
+                    if ui
+                        .selectable_label(self.serial_odd == Some(odd), name)
+                        .clicked()
+                    {
+                        self.serial_odd = Some(odd);
+                    }
+                }
+            });
+        }
+
+        match solution {
+            Some(solution) => ui.label(
+                RichText::new(format!("Cut the {} wire.", ordinal(solution.wire + 1)))
+                    .strong()
+                    .color(Color32::GREEN),
+            ),
+            None => ui.label("Which way does the serial number end?"),
+        };
+        ui.label(format!("\n{count} Wires:"));
+        for (i, rule) in rules.iter().enumerate() {
+            let text = RichText::new(*rule);
+            ui.label(if solution.is_some_and(|solution| solution.rule == i) {
+                text.strong().background_color(Color32::DARK_GREEN)
+            } else {
+                text
+            });
+        }
+    }
+
+    fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    fn is_solved(&self, bomb: &Bomb) -> bool {
+        self.solution(bomb.facts.serial_odd.or(self.serial_odd))
+            .is_some()
+    }
+}
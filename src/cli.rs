@@ -0,0 +1,876 @@
+//! Solves modules from the command line, so the rules can be scripted and
+//! tested without a window. With no arguments, reads one command per line
+//! from stdin.
+
+use std::collections::HashMap;
+use std::io::BufRead;
+use std::process::ExitCode;
+
+use strum::IntoEnumIterator;
+
+use ktane_android::solver::button::{self, Action, ButtonColor, ButtonLabel, StripColor};
+use ktane_android::solver::complicated_wires::{self, ComplicatedWire};
+use ktane_android::solver::edgework::{Edgework, Facts};
+use ktane_android::solver::knobs::{self, Leds};
+use ktane_android::solver::mazes::{self, Cell};
+use ktane_android::solver::memory::{self, Stage};
+use ktane_android::solver::simon_says::{self, SimonColor};
+use ktane_android::solver::wire_sequences::{self, Panel, SequenceColor, Terminal, Wire};
+use ktane_android::solver::wires::{self, WireColor};
+use ktane_android::solver::{keypad, morse_code, ordinal, passwords, whos_on_first};
+
+#[cfg(feature = "server")]
+mod server;
+
+const USAGE: &str = "\
+Usage: ktane_cli <module> [arguments]
+       ktane_cli < commands.txt
+
+Modules:
+  wires <colors> [--serial SERIAL]
+      Colors from top to bottom as letters: R, B, Y, W, K (black).
+  button <color> <label> [--batteries N] [--car] [--frk] [--strip COLOR]
+  keypad <symbol> <symbol> <symbol> <symbol>
+      Symbols by name or nickname, e.g. balloon at lambda lightning.
+  simon-says <flashes> [--serial SERIAL] [--strikes N]
+      Flashes as letters: R, B, G, Y.
+  whos-on-first <display> <button> <button> <button> <button> <button> <button>
+      Words in full or as their first and last three letters; \"\" for an
+      empty display.
+  memory <display:labels>...
+      One argument per stage so far, e.g. 2:4132 for display 2 and labels
+      4, 1, 3, 2 from left to right.
+  morse-code <letters> [--looped]
+      --looped when the letters were not received right after the pause.
+  complicated-wires <wire>... [--serial SERIAL] [--batteries N] [--parallel]
+      One argument per wire with the letters that apply: L (LED lit),
+      S (star), B (blue), R (red), or - for a plain white wire.
+  wire-sequences <panel>...
+      One argument per panel so far, each three wires from top to bottom
+      separated by commas: color R, B or K (black) and terminal A, B or C,
+      or - when missing, e.g. RC,-,KA.
+  mazes <marker> <start> <goal>
+      Cells as column,row counted from 1 at the top left: either circle
+      marker, the white light and the red triangle.
+  passwords [--col1 LETTERS] ... [--col5 LETTERS]
+  knobs <top> <bottom>
+      The two rows of six LEDs from left to right, 1 when lit and 0 when
+      not, e.g. 001011 111101.
+  serve [--port PORT]
+      Answers JSON requests on localhost, see GET /schema. Needs the server
+      feature.";
+
+enum Error {
+    /// The arguments do not make up a command, so the usage is shown.
+    Usage(String),
+    /// The input cannot be on a real module, e.g. an unknown color.
+    Invalid(String),
+}
+
+/// Positional arguments and `--name [value]` options.
+struct Args {
+    positional: Vec<String>,
+    options: HashMap<String, String>,
+}
+
+impl Args {
+    /// Parses `args`, accepting the options in `values`, which take a value,
+    /// and `flags`, which do not.
+    fn parse(args: &[String], values: &[&str], flags: &[&str]) -> Result<Args, Error> {
+        let mut parsed = Args {
+            positional: vec![],
+            options: HashMap::new(),
+        };
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            let Some(name) = arg.strip_prefix("--") else {
+                parsed.positional.push(arg.clone());
+                continue;
+            };
+            let value = if flags.contains(&name) {
+                String::new()
+            } else if values.contains(&name) {
+                args.next()
+                    .ok_or_else(|| Error::Usage(format!("--{name} needs a value")))?
+                    .clone()
+            } else {
+                return Err(Error::Usage(format!("unknown option --{name}")));
+            };
+            parsed.options.insert(name.to_owned(), value);
+        }
+        Ok(parsed)
+    }
+
+    fn option(&self, name: &str) -> Option<&str> {
+        self.options.get(name).map(String::as_str)
+    }
+
+    fn flag(&self, name: &str) -> bool {
+        self.options.contains_key(name)
+    }
+
+    fn number(&self, name: &str) -> Result<u8, Error> {
+        self.option(name).map_or(Ok(0), |value| {
+            value
+                .parse()
+                .map_err(|_| Error::Invalid(format!("--{name} must be a number, got {value}")))
+        })
+    }
+
+    /// The facts from `--serial`, if given.
+    fn serial(&self) -> Result<Option<Facts>, Error> {
+        self.option("serial").map(serial).transpose()
+    }
+}
+
+/// The facts from a serial number.
+fn serial(serial: &str) -> Result<Facts, Error> {
+    let edgework = Edgework {
+        serial: serial.to_uppercase(),
+        ..Edgework::default()
+    };
+    if !edgework.serial_valid() {
+        return Err(Error::Invalid(format!(
+            "{serial} is not a serial number: expected six letters and digits ending in a digit"
+        )));
+    }
+    Ok(edgework.facts())
+}
+
+/// Finds the variant of `E` named `name`, ignoring case.
+fn variant<E: IntoEnumIterator + AsRef<str>>(kind: &str, name: &str) -> Result<E, Error> {
+    E::iter()
+        .find(|variant| variant.as_ref().eq_ignore_ascii_case(name))
+        .ok_or_else(|| {
+            let names: Vec<_> = E::iter()
+                .map(|variant| variant.as_ref().to_owned())
+                .collect();
+            Error::Invalid(format!(
+                "unknown {kind} {name}, expected one of {}",
+                names.join(", ")
+            ))
+        })
+}
+
+/// Runs `solve` for both answers to a yes or no question about the serial
+/// number, succeeding when the answer does not depend on it.
+fn either<T: PartialEq>(
+    known: Option<bool>,
+    mut solve: impl FnMut(bool) -> Result<T, Error>,
+) -> Result<T, Error> {
+    match known {
+        Some(known) => solve(known),
+        None => {
+            let answer = solve(false)?;
+            if answer == solve(true)? {
+                Ok(answer)
+            } else {
+                Err(Error::Usage(
+                    "the answer depends on the serial number, which was not given".to_owned(),
+                ))
+            }
+        }
+    }
+}
+
+fn wires(args: &[String]) -> Result<String, Error> {
+    let args = Args::parse(args, &["serial"], &[])?;
+    let colors = args
+        .positional
+        .concat()
+        .chars()
+        .map(|letter| match letter.to_ascii_uppercase() {
+            'R' => Ok(WireColor::Red),
+            'B' => Ok(WireColor::Blue),
+            'Y' => Ok(WireColor::Yellow),
+            'W' => Ok(WireColor::White),
+            'K' => Ok(WireColor::Black),
+            _ => Err(Error::Invalid(format!(
+                "unknown wire color {letter}, expected R, B, Y, W or K"
+            ))),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let odd = args.serial()?.and_then(|facts| facts.serial_odd);
+    let solution = either(odd, |odd| {
+        wires::solve(&colors, odd)
+            .map(|solution| solution.wire)
+            .map_err(|error| Error::Invalid(error.to_string()))
+    })?;
+    Ok(format!("Cut the {} wire.", ordinal(solution + 1)))
+}
+
+fn button(args: &[String]) -> Result<String, Error> {
+    let args = Args::parse(args, &["batteries", "strip"], &["car", "frk"])?;
+    let [color, label] = &args.positional[..] else {
+        return Err(Error::Usage("expected a color and a label".to_owned()));
+    };
+    let action = button::solve(
+        variant::<ButtonColor>("color", color)?,
+        variant::<ButtonLabel>("label", label)?,
+        args.number("batteries")?,
+        args.flag("car"),
+        args.flag("frk"),
+    );
+    Ok(match (action, args.option("strip")) {
+        (Action::Press, _) => "Press and immediately release.".to_owned(),
+        (Action::Hold, Some(strip)) => format!(
+            "Hold, then release when the timer has a {} in any position.",
+            button::release_digit(variant::<StripColor>("strip color", strip)?)
+        ),
+        (Action::Hold, None) => {
+            let digits: Vec<_> = StripColor::iter()
+                .map(|strip| format!("{} {}", strip.as_ref(), button::release_digit(strip)))
+                .collect();
+            format!(
+                "Hold, then release when the timer shows the digit for the strip: {}.",
+                digits.join(", ")
+            )
+        }
+    })
+}
+
+fn keypad(args: &[String]) -> Result<String, Error> {
+    let args = Args::parse(args, &[], &[])?;
+    let buttons = args
+        .positional
+        .iter()
+        .map(|name| {
+            keypad::lookup(name)
+                .ok_or_else(|| Error::Invalid(format!("no single symbol is called {name}")))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let order = keypad::solve(&buttons).map_err(|error| Error::Invalid(error.to_string()))?;
+    let names: Vec<_> = order.iter().map(|button| button.name()).collect();
+    Ok(format!("Press {}.", names.join(", ")))
+}
+
+fn simon_says(args: &[String]) -> Result<String, Error> {
+    let args = Args::parse(args, &["serial", "strikes"], &[])?;
+    let flashes = args
+        .positional
+        .concat()
+        .chars()
+        .map(|letter| match letter.to_ascii_uppercase() {
+            'R' => Ok(SimonColor::Red),
+            'B' => Ok(SimonColor::Blue),
+            'G' => Ok(SimonColor::Green),
+            'Y' => Ok(SimonColor::Yellow),
+            _ => Err(Error::Invalid(format!(
+                "unknown flash {letter}, expected R, B, G or Y"
+            ))),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let strikes = args.number("strikes")?;
+    let vowel = args.serial()?.and_then(|facts| facts.serial_vowel);
+    let presses = either(vowel, |vowel| {
+        simon_says::solve(&flashes, vowel, strikes)
+            .map_err(|error| Error::Invalid(error.to_string()))
+    })?;
+    let names: Vec<_> = presses.iter().map(|color| color.as_ref()).collect();
+    Ok(format!("Press {}.", names.join(", ")))
+}
+
+fn whos_on_first(args: &[String]) -> Result<String, Error> {
+    let args = Args::parse(args, &[], &[])?;
+    let [display, buttons @ ..] = &args.positional[..] else {
+        return Err(Error::Usage(
+            "expected the display and six buttons".to_owned(),
+        ));
+    };
+    let display = whos_on_first::display_from_abbreviation(&whos_on_first::abbreviate(
+        &display.to_ascii_uppercase(),
+    ))
+    .ok_or_else(|| Error::Invalid(format!("unknown display word {display}")))?;
+    let buttons = buttons
+        .iter()
+        .map(|word| {
+            whos_on_first::button_from_abbreviation(&whos_on_first::abbreviate(
+                &word.to_ascii_uppercase(),
+            ))
+            .ok_or_else(|| Error::Invalid(format!("unknown button word {word}")))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let buttons = <[&str; 6]>::try_from(buttons)
+        .map_err(|_| Error::Usage("expected the display and six buttons".to_owned()))?;
+    let position = whos_on_first::solve(display, &buttons)
+        .map_err(|error| Error::Invalid(error.to_string()))?;
+    Ok(format!(
+        "Press the {} button, {}.",
+        position.name(),
+        buttons[position.index()]
+    ))
+}
+
+fn memory(args: &[String]) -> Result<String, Error> {
+    let args = Args::parse(args, &[], &[])?;
+    if args.positional.is_empty() {
+        return Err(Error::Usage("expected at least one stage".to_owned()));
+    }
+    let mut history: Vec<Stage> = vec![];
+    let mut pressed = None;
+    for stage in &args.positional {
+        let invalid = || Error::Invalid(format!("{stage} is not a display and four labels"));
+        let (display, labels) = stage.split_once(':').ok_or_else(invalid)?;
+        let display = display.parse().map_err(|_| invalid())?;
+        let labels = labels
+            .chars()
+            .map(|label| label.to_digit(10).map(|label| label as u8))
+            .collect::<Option<Vec<_>>>()
+            .and_then(|labels| <[u8; 4]>::try_from(labels).ok())
+            .ok_or_else(invalid)?;
+        pressed = Some(
+            memory::solve(&history, display, labels)
+                .map_err(|error| Error::Invalid(error.to_string()))?,
+        );
+        history.extend(pressed.map(Stage::from));
+    }
+    let pressed = pressed.expect("there is at least one stage");
+    Ok(format!(
+        "Press the {} button, labeled {}.",
+        ordinal(pressed.position as usize),
+        pressed.label
+    ))
+}
+
+fn morse_code(args: &[String]) -> Result<String, Error> {
+    let args = Args::parse(args, &[], &["looped"])?;
+    let letters = args.positional.concat().to_uppercase();
+    let words = if args.flag("looped") {
+        morse_code::solve_looped(&letters)
+    } else {
+        morse_code::solve(&letters)
+    };
+    if words.is_empty() {
+        return Err(Error::Invalid(format!("no word matches {letters}")));
+    }
+    let words: Vec<_> = words
+        .iter()
+        .map(|word| {
+            format!(
+                "{} {}.{:03} MHz",
+                word.word,
+                word.frequency / 1000,
+                word.frequency % 1000
+            )
+        })
+        .collect();
+    Ok(words.join("\n"))
+}
+
+fn complicated_wires(args: &[String]) -> Result<String, Error> {
+    let args = Args::parse(args, &["serial", "batteries"], &["parallel"])?;
+    if args.positional.is_empty() {
+        return Err(Error::Usage("expected at least one wire".to_owned()));
+    }
+    let wires = args
+        .positional
+        .iter()
+        .map(|wire| {
+            let mut parsed = ComplicatedWire::default();
+            for letter in wire.chars() {
+                let attribute = match letter.to_ascii_uppercase() {
+                    'L' => &mut parsed.led,
+                    'S' => &mut parsed.star,
+                    'B' => &mut parsed.blue,
+                    'R' => &mut parsed.red,
+                    '-' => continue,
+                    _ => {
+                        return Err(Error::Invalid(format!(
+                            "unknown wire letter {letter}, expected L, S, B, R or -"
+                        )))
+                    }
+                };
+                *attribute = true;
+            }
+            Ok(parsed)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let batteries = args.number("batteries")?;
+    let odd = args.serial()?.and_then(|facts| facts.serial_odd);
+    let answers = wires
+        .iter()
+        .enumerate()
+        .map(|(i, wire)| {
+            let cut = either(odd, |odd| {
+                Ok(complicated_wires::solve(
+                    *wire,
+                    !odd,
+                    args.flag("parallel"),
+                    batteries,
+                ))
+            })?;
+            Ok(format!(
+                "{} wire: {}",
+                ordinal(i + 1),
+                if cut { "cut" } else { "don't cut" }
+            ))
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+    Ok(answers.join("\n"))
+}
+
+fn wire_sequences(args: &[String]) -> Result<String, Error> {
+    let args = Args::parse(args, &[], &[])?;
+    if args.positional.is_empty() {
+        return Err(Error::Usage("expected at least one panel".to_owned()));
+    }
+    let panels = args
+        .positional
+        .iter()
+        .map(|panel| {
+            let invalid = || Error::Invalid(format!("{panel} is not three wires like RC,-,KA"));
+            let wires = panel
+                .split(',')
+                .map(|wire| {
+                    if wire == "-" {
+                        return Ok(None);
+                    }
+                    let mut letters = wire.chars().map(|letter| letter.to_ascii_uppercase());
+                    let color = match letters.next() {
+                        Some('R') => SequenceColor::Red,
+                        Some('B') => SequenceColor::Blue,
+                        Some('K') => SequenceColor::Black,
+                        _ => return Err(invalid()),
+                    };
+                    let terminal = match letters.next() {
+                        Some('A') => Terminal::A,
+                        Some('B') => Terminal::B,
+                        Some('C') => Terminal::C,
+                        _ => return Err(invalid()),
+                    };
+                    match letters.next() {
+                        None => Ok(Some(Wire { color, terminal })),
+                        Some(_) => Err(invalid()),
+                    }
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            <Panel>::try_from(wires).map_err(|_| invalid())
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let cuts =
+        wire_sequences::solve_panel(&panels).map_err(|error| Error::Invalid(error.to_string()))?;
+    let answers: Vec<_> = cuts
+        .iter()
+        .enumerate()
+        .filter_map(|(i, cut)| {
+            let cut = (*cut)?;
+            Some(format!(
+                "{} wire: {}",
+                ordinal(i + 1),
+                if cut { "cut" } else { "don't cut" }
+            ))
+        })
+        .collect();
+    if answers.is_empty() {
+        return Ok("The panel has no wires.".to_owned());
+    }
+    Ok(answers.join("\n"))
+}
+
+/// A cell given as column,row counted from 1 at the top left.
+fn cell(cell: &str) -> Result<Cell, Error> {
+    let invalid = || {
+        Error::Invalid(format!(
+            "{cell} is not a column and row from 1 to 6 like 1,2"
+        ))
+    };
+    let (column, row) = cell.split_once(',').ok_or_else(invalid)?;
+    let number = |number: &str| {
+        number
+            .trim()
+            .parse::<u8>()
+            .ok()
+            .filter(|number| (1..=6).contains(number))
+            .map(|number| number - 1)
+            .ok_or_else(invalid)
+    };
+    Ok(Cell {
+        column: number(column)?,
+        row: number(row)?,
+    })
+}
+
+fn mazes(args: &[String]) -> Result<String, Error> {
+    let args = Args::parse(args, &[], &[])?;
+    let [marker, start, goal] = &args.positional[..] else {
+        return Err(Error::Usage(
+            "expected a marker, the start and the goal".to_owned(),
+        ));
+    };
+    let maze = mazes::identify(cell(marker)?).map_err(|error| Error::Invalid(error.to_string()))?;
+    let moves = mazes::solve(&mazes::MAZES[maze], cell(start)?, cell(goal)?)
+        .map_err(|error| Error::Invalid(error.to_string()))?;
+    if moves.is_empty() {
+        return Ok(format!("Maze {}: already at the goal.", maze + 1));
+    }
+    let names: Vec<_> = moves.iter().map(|direction| direction.as_ref()).collect();
+    Ok(format!("Maze {}: {}.", maze + 1, names.join(", ")))
+}
+
+fn passwords(args: &[String]) -> Result<String, Error> {
+    const COLUMNS: [&str; 5] = ["col1", "col2", "col3", "col4", "col5"];
+    let args = Args::parse(args, &COLUMNS, &[])?;
+    if !args.positional.is_empty() {
+        return Err(Error::Usage("letters go after --col1 to --col5".to_owned()));
+    }
+    let columns = COLUMNS.map(|column| args.option(column).unwrap_or_default().to_owned());
+    let words = passwords::solve(&columns);
+    if words.is_empty() {
+        return Err(Error::Invalid(
+            "no password matches these letters".to_owned(),
+        ));
+    }
+    Ok(words.join("\n"))
+}
+
+fn knobs(args: &[String]) -> Result<String, Error> {
+    let args = Args::parse(args, &[], &[])?;
+    let [top, bottom] = &args.positional[..] else {
+        return Err(Error::Usage(
+            "expected the top and bottom rows of LEDs".to_owned(),
+        ));
+    };
+    let mut leds = Leds::default();
+    for (row, text) in leds.iter_mut().zip([top, bottom]) {
+        *row = text
+            .chars()
+            .map(|led| match led {
+                '1' => Some(true),
+                '0' => Some(false),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()
+            .and_then(|row| <[bool; 6]>::try_from(row).ok())
+            .ok_or_else(|| Error::Invalid(format!("{text} is not six LEDs as 1 or 0")))?;
+    }
+    let position = knobs::solve(&leds).map_err(|error| Error::Invalid(error.to_string()))?;
+    Ok(format!("Turn the knob to {}.", position.as_ref()))
+}
+
+#[cfg(feature = "server")]
+fn serve(args: &[String]) -> Result<String, Error> {
+    let args = Args::parse(args, &["port"], &[])?;
+    let port = args.option("port").unwrap_or("7878");
+    let port = port
+        .parse()
+        .map_err(|_| Error::Usage(format!("--port must be a port number, got {port}")))?;
+    server::serve(port).map_err(Error::Invalid)?;
+    Ok(String::new())
+}
+
+fn run(args: &[String]) -> Result<String, Error> {
+    let Some((module, args)) = args.split_first() else {
+        return Err(Error::Usage("expected a module".to_owned()));
+    };
+    match module.as_str() {
+        "wires" => wires(args),
+        "button" => button(args),
+        "keypad" => keypad(args),
+        "simon-says" => simon_says(args),
+        "whos-on-first" => whos_on_first(args),
+        "memory" => memory(args),
+        "morse-code" => morse_code(args),
+        "complicated-wires" => complicated_wires(args),
+        "wire-sequences" => wire_sequences(args),
+        "mazes" => mazes(args),
+        "passwords" => passwords(args),
+        "knobs" => knobs(args),
+        #[cfg(feature = "server")]
+        "serve" => serve(args),
+        "help" | "--help" | "-h" => Ok(USAGE.to_owned()),
+        _ => Err(Error::Usage(format!("unknown module {module}"))),
+    }
+}
+
+/// Splits a line of stdin into arguments at spaces, keeping "quoted text"
+/// together.
+fn split(line: &str) -> Vec<String> {
+    let mut args = vec![];
+    let mut arg: Option<String> = None;
+    let mut quoted = false;
+    for c in line.chars() {
+        match c {
+            '"' => {
+                quoted = !quoted;
+                arg.get_or_insert_with(String::new);
+            }
+            c if c.is_whitespace() && !quoted => args.extend(arg.take()),
+            c => arg.get_or_insert_with(String::new).push(c),
+        }
+    }
+    args.extend(arg);
+    args
+}
+
+/// Prints the answer or the error, returning whether the command succeeded.
+fn report(args: &[String]) -> bool {
+    match run(args) {
+        Ok(answer) => {
+            println!("{answer}");
+            true
+        }
+        Err(Error::Usage(message)) => {
+            eprintln!("error: {message}\n\n{USAGE}");
+            false
+        }
+        Err(Error::Invalid(message)) => {
+            eprintln!("error: {message}");
+            false
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let success = if args.is_empty() {
+        let mut success = true;
+        for line in std::io::stdin().lock().lines() {
+            let line = match line {
+                Ok(line) => line,
+                Err(err) => {
+                    eprintln!("error: failed to read stdin: {err}");
+                    return ExitCode::FAILURE;
+                }
+            };
+            let args = split(&line);
+            if !args.is_empty() {
+                success &= report(&args);
+            }
+        }
+        success
+    } else {
+        report(&args)
+    };
+    if success {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn answer(line: &str) -> Result<String, Error> {
+        run(&split(line))
+    }
+
+    fn is_usage(result: Result<String, Error>) -> bool {
+        matches!(result, Err(Error::Usage(_)))
+    }
+
+    fn is_invalid(result: Result<String, Error>) -> bool {
+        matches!(result, Err(Error::Invalid(_)))
+    }
+
+    #[test]
+    fn splitting() {
+        assert_eq!(split("  wires  RBB "), ["wires", "RBB"]);
+        assert_eq!(
+            split(r#"whos-on-first "" "you are" no"#),
+            ["whos-on-first", "", "you are", "no"]
+        );
+        assert!(split("   ").is_empty());
+    }
+
+    #[test]
+    fn options() {
+        assert!(is_usage(answer("wires RBB --colour")));
+        assert!(is_usage(answer("wires RBB --serial")));
+        assert!(is_invalid(answer("button red press --batteries x")));
+        assert!(is_usage(answer("frobnicate")));
+        assert!(is_usage(run(&[])));
+        assert!(answer("help").is_ok());
+    }
+
+    #[test]
+    fn wires() {
+        let cut = "Cut the 2nd wire.";
+        assert_eq!(
+            answer("wires RBBYW --serial AB3DE4").ok().as_deref(),
+            Some(cut)
+        );
+        assert_eq!(answer("wires r b b y w").ok().as_deref(), Some(cut));
+        assert_eq!(
+            answer("wires RWB").ok().as_deref(),
+            Some("Cut the 3rd wire.")
+        );
+        // Four wires with more than one red cut the last red only on an odd
+        // serial number.
+        assert!(is_usage(answer("wires RRBY")));
+        assert!(is_invalid(answer("wires RBX")));
+        assert!(is_invalid(answer("wires RBBYWKKR")));
+        assert!(is_invalid(answer("wires RBB --serial ABCDEF")));
+    }
+
+    #[test]
+    fn button() {
+        assert!(answer("button blue abort")
+            .unwrap_or_default()
+            .starts_with("Hold"));
+        assert_eq!(
+            answer("button Blue Abort --strip yellow").ok().as_deref(),
+            Some("Hold, then release when the timer has a 5 in any position.")
+        );
+        assert_eq!(
+            answer("button white detonate --batteries 3")
+                .ok()
+                .as_deref(),
+            Some("Press and immediately release.")
+        );
+        assert!(is_usage(answer("button red")));
+        assert!(is_invalid(answer("button green press")));
+    }
+
+    #[test]
+    fn keypad() {
+        assert_eq!(
+            answer("keypad lightning balloon lambda at").ok().as_deref(),
+            Some("Press O, A, Lambda, N.")
+        );
+        assert!(is_invalid(answer("keypad omega psi ae balloon")));
+        assert!(is_invalid(answer("keypad balloon at lambda squi")));
+    }
+
+    #[test]
+    fn simon_says() {
+        assert_eq!(
+            answer("simon-says RBG --serial AB3DE4 --strikes 1")
+                .ok()
+                .as_deref(),
+            Some("Press Yellow, Green, Blue.")
+        );
+        assert_eq!(
+            answer("simon-says rbg --serial 123456").ok().as_deref(),
+            Some("Press Blue, Yellow, Green.")
+        );
+        assert!(is_usage(answer("simon-says RBG")));
+        assert!(is_invalid(answer("simon-says RBW --serial 123456")));
+    }
+
+    #[test]
+    fn whos_on_first() {
+        let pressed = Some("Press the bottom right button, FIRST.");
+        assert_eq!(
+            answer("whos-on-first YES READY NO YES WAIT BLANK FIRST")
+                .ok()
+                .as_deref(),
+            pressed
+        );
+        assert_eq!(
+            answer("whos-on-first yes ready no yes wait blank first")
+                .ok()
+                .as_deref(),
+            pressed
+        );
+        assert_eq!(
+            answer(r#"whos-on-first "" READY NO YES WAIT BLANK FIRST"#)
+                .ok()
+                .as_deref(),
+            Some("Press the middle right button, WAIT.")
+        );
+        assert!(is_usage(answer("whos-on-first yes READY NO")));
+        assert!(is_invalid(answer(
+            "whos-on-first maybe READY NO YES WAIT BLANK FIRST"
+        )));
+    }
+
+    #[test]
+    fn memory() {
+        assert_eq!(
+            answer("memory 3:2413 1:4312").ok().as_deref(),
+            Some("Press the 1st button, labeled 4.")
+        );
+        assert!(is_usage(answer("memory")));
+        assert!(is_invalid(answer("memory 5:1234")));
+        assert!(is_invalid(answer("memory 1:123")));
+        assert!(is_invalid(answer("memory 1-1234")));
+        assert!(is_invalid(answer("memory 1:1123")));
+    }
+
+    #[test]
+    fn morse_code() {
+        assert_eq!(
+            answer("morse-code she").ok().as_deref(),
+            Some("SHELL 3.505 MHz")
+        );
+        assert_eq!(
+            answer("morse-code ICK --looped").ok().as_deref(),
+            Some("SLICK 3.522 MHz\nTRICK 3.532 MHz\nFLICK 3.555 MHz\nBRICK 3.575 MHz")
+        );
+        assert!(is_invalid(answer("morse-code QQQ")));
+    }
+
+    #[test]
+    fn complicated_wires() {
+        assert_eq!(
+            answer("complicated-wires - LR sb --serial AB3DE4 --batteries 2")
+                .ok()
+                .as_deref(),
+            Some("1st wire: cut\n2nd wire: cut\n3rd wire: don't cut")
+        );
+        assert_eq!(
+            answer("complicated-wires LSB --parallel").ok().as_deref(),
+            Some("1st wire: cut")
+        );
+        assert!(is_usage(answer("complicated-wires R")));
+        assert!(is_usage(answer("complicated-wires")));
+        assert!(is_invalid(answer("complicated-wires LX")));
+    }
+
+    #[test]
+    fn wire_sequences() {
+        assert_eq!(
+            answer("wire-sequences RC,-,BA -,RA,-").ok().as_deref(),
+            Some("2nd wire: don't cut")
+        );
+        assert_eq!(
+            answer("wire-sequences rc,kb,-").ok().as_deref(),
+            Some("1st wire: cut\n2nd wire: cut")
+        );
+        assert!(is_usage(answer("wire-sequences")));
+        assert!(is_invalid(answer("wire-sequences RC,-")));
+        assert!(is_invalid(answer("wire-sequences RD,-,-")));
+    }
+
+    #[test]
+    fn mazes() {
+        assert_eq!(
+            answer("mazes 1,2 1,1 3,1").ok().as_deref(),
+            Some("Maze 1: Right, Right.")
+        );
+        assert!(is_usage(answer("mazes 1,2 1,1")));
+        assert!(is_invalid(answer("mazes 1,2 1,1 7,1")));
+        assert!(is_invalid(answer("mazes 6,6 1,1 3,1")));
+    }
+
+    #[test]
+    fn knobs() {
+        assert_eq!(
+            answer("knobs 001011 111101").ok().as_deref(),
+            Some("Turn the knob to Up.")
+        );
+        assert!(is_usage(answer("knobs 001011")));
+        assert!(is_invalid(answer("knobs 00101 111101")));
+        assert!(is_invalid(answer("knobs 000000 000000")));
+    }
+
+    #[test]
+    fn passwords() {
+        assert_eq!(
+            answer("passwords --col1 WPS --col2 HLP").ok().as_deref(),
+            Some("PLACE\nPLANT\nSPELL\nWHERE\nWHICH")
+        );
+        assert!(is_invalid(answer("passwords --col1 ABCDEF --col3 XYZ")));
+        assert!(is_usage(answer("passwords WPS")));
+    }
+
+    #[test]
+    fn either_serial() {
+        let parity = |odd| Ok::<_, Error>(odd);
+        assert!(matches!(either(Some(true), parity), Ok(true)));
+        assert!(matches!(either(None, parity), Err(Error::Usage(_))));
+        assert!(matches!(either(None, |_| Ok::<_, Error>(1)), Ok(1)));
+    }
+}
@@ -1,32 +1,51 @@
 #![windows_subsystem = "windows"]
 
+#[cfg(feature = "gui")]
 mod app;
+pub mod solver;
+
+#[cfg(feature = "gui")]
 use egui_wgpu::wgpu;
+#[cfg(feature = "gui")]
 use egui_winit::winit;
 
+#[cfg(feature = "gui")]
 use winit::event_loop::{EventLoop, EventLoopBuilder, EventLoopWindowTarget};
 
-#[cfg(target_os = "android")]
+#[cfg(all(feature = "gui", target_os = "android"))]
 use winit::platform::android::activity::AndroidApp;
 
+#[cfg(feature = "gui")]
+use std::time::Instant;
+
+#[cfg(feature = "gui")]
 use winit::event_loop::ControlFlow;
 
+#[cfg(feature = "gui")]
 use egui_wgpu::winit::Painter;
+#[cfg(feature = "gui")]
 use egui_winit::State;
+#[cfg(feature = "gui")]
 use winit::event::Event::*;
 
+#[cfg(feature = "gui")]
 const INITIAL_WIDTH: u32 = 600;
+#[cfg(feature = "gui")]
 const INITIAL_HEIGHT: u32 = 600;
 
+#[cfg(feature = "gui")]
 /// A custom event type for the winit app.
 enum Event {
-    RequestRedraw,
+    /// Redraw once this time is reached.
+    RequestRedraw(Instant),
 }
 
+#[cfg(feature = "gui")]
 /// Enable egui to request redraws via a custom Winit event...
 #[derive(Clone)]
 struct RepaintSignal(std::sync::Arc<std::sync::Mutex<winit::event_loop::EventLoopProxy<Event>>>);
 
+#[cfg(feature = "gui")]
 fn create_window<T>(
     event_loop: &EventLoopWindowTarget<T>,
     state: &mut State,
@@ -65,19 +84,23 @@ fn create_window<T>(
     Some(window)
 }
 
-fn _main(event_loop: EventLoop<Event>) {
+#[cfg(feature = "gui")]
+/// Runs the app, saving its state in the `storage` directory.
+fn _main(event_loop: EventLoop<Event>, storage: Option<std::path::PathBuf>) {
     let ctx = egui::Context::default();
     let repaint_signal = RepaintSignal(std::sync::Arc::new(std::sync::Mutex::new(
         event_loop.create_proxy(),
     )));
-    ctx.set_request_repaint_callback(move |_info| {
+    ctx.set_request_repaint_callback(move |info| {
         log::debug!("Request Repaint Callback");
-        repaint_signal
-            .0
-            .lock()
-            .unwrap()
-            .send_event(Event::RequestRedraw)
-            .ok();
+        if let Some(when) = Instant::now().checked_add(info.after) {
+            repaint_signal
+                .0
+                .lock()
+                .unwrap()
+                .send_event(Event::RequestRedraw(when))
+                .ok();
+        }
     });
 
     let mut state = State::new(&event_loop);
@@ -98,86 +121,105 @@ fn _main(event_loop: EventLoop<Event>) {
         false,
     );
     let mut window: Option<winit::window::Window> = None;
-    let mut application = app::Application::new(&ctx);
+    let mut application = app::Application::new(&ctx, storage);
+    // When egui next wants to be redrawn without any new input.
+    let mut next_redraw: Option<Instant> = None;
 
-    event_loop.run(move |event, event_loop, control_flow| match event {
-        Resumed => match window {
-            None => {
-                window = create_window(event_loop, &mut state, &mut painter);
-            }
-            Some(ref window) => {
-                pollster::block_on(painter.set_window(Some(window))).unwrap_or_else(|err| {
-                    log::error!(
-                        "Failed to associate window with painter after resume event: {err:?}"
-                    )
-                });
-                window.request_redraw();
-            }
-        },
-        Suspended => {
-            window = None;
-        }
-        RedrawRequested(..) => {
-            if let Some(window) = window.as_ref() {
-                log::debug!("RedrawRequested, with window set");
-                let raw_input = state.take_egui_input(window);
-
-                log::debug!("RedrawRequested: calling ctx.run()");
-                let full_output = ctx.run(raw_input, |ctx| {
-                    application.update(&ctx);
-                });
-                log::debug!("RedrawRequested: called ctx.run()");
-                state.handle_platform_output(window, &ctx, full_output.platform_output);
-
-                log::debug!("RedrawRequested: calling paint_and_update_textures()");
-                painter.paint_and_update_textures(
-                    state.pixels_per_point(),
-                    [0.0, 0.0, 0.0, 0.0],
-                    &ctx.tessellate(full_output.shapes),
-                    &full_output.textures_delta,
-                    false, // capture
-                );
-
-                if full_output.repaint_after.is_zero() {
+    event_loop.run(move |event, event_loop, control_flow| {
+        match event {
+            Resumed => match window {
+                None => {
+                    window = create_window(event_loop, &mut state, &mut painter);
+                }
+                Some(ref window) => {
+                    pollster::block_on(painter.set_window(Some(window))).unwrap_or_else(|err| {
+                        log::error!(
+                            "Failed to associate window with painter after resume event: {err:?}"
+                        )
+                    });
                     window.request_redraw();
                 }
-            } else {
-                log::debug!("RedrawRequested, with no window set");
+            },
+            Suspended => {
+                window = None;
             }
-        }
-        MainEventsCleared | UserEvent(Event::RequestRedraw) => {
-            if let Some(window) = window.as_ref() {
-                log::debug!("Winit event (main events cleared or user event) - request_redraw()");
-                window.request_redraw();
+            RedrawRequested(..) => {
+                if let Some(window) = window.as_ref() {
+                    log::debug!("RedrawRequested, with window set");
+                    let raw_input = state.take_egui_input(window);
+
+                    log::debug!("RedrawRequested: calling ctx.run()");
+                    let full_output = ctx.run(raw_input, |ctx| {
+                        application.update(ctx);
+                    });
+                    log::debug!("RedrawRequested: called ctx.run()");
+                    state.handle_platform_output(window, &ctx, full_output.platform_output);
+
+                    log::debug!("RedrawRequested: calling paint_and_update_textures()");
+                    painter.paint_and_update_textures(
+                        state.pixels_per_point(),
+                        [0.0, 0.0, 0.0, 0.0],
+                        &ctx.tessellate(full_output.shapes),
+                        &full_output.textures_delta,
+                        false, // capture
+                    );
+
+                    // Zero means right away; egui asks for the maximum duration
+                    // when nothing needs to be redrawn.
+                    next_redraw = Instant::now().checked_add(full_output.repaint_after);
+                } else {
+                    log::debug!("RedrawRequested, with no window set");
+                }
             }
-        }
-        WindowEvent { event, .. } => {
-            log::debug!("Window Event: {event:?}");
-            match event {
-                winit::event::WindowEvent::Resized(size) => {
-                    if size.width > 0 && size.height > 0 {
+            UserEvent(Event::RequestRedraw(when)) => {
+                next_redraw = Some(next_redraw.map_or(when, |next| next.min(when)));
+            }
+            WindowEvent { event, .. } => {
+                log::debug!("Window Event: {event:?}");
+                match event {
+                    winit::event::WindowEvent::Resized(size)
+                        if size.width > 0 && size.height > 0 =>
+                    {
                         painter.on_window_resized(size.width, size.height);
                     }
+                    winit::event::WindowEvent::CloseRequested => {
+                        *control_flow = ControlFlow::Exit;
+                    }
+                    _ => {}
                 }
-                winit::event::WindowEvent::CloseRequested => {
-                    *control_flow = ControlFlow::Exit;
+
+                let response = state.on_event(&ctx, &event);
+                if response.repaint {
+                    if let Some(window) = window.as_ref() {
+                        window.request_redraw();
+                    }
                 }
-                _ => {}
             }
+            _ => (),
+        }
 
-            let response = state.on_event(&ctx, &event);
-            if response.repaint {
+        // Sleep until the next event or until egui wants to be redrawn,
+        // rather than redrawing continuously.
+        if let ControlFlow::ExitWithCode(_) = *control_flow {
+            return;
+        }
+        *control_flow = match next_redraw {
+            Some(when) if when <= Instant::now() => {
+                next_redraw = None;
                 if let Some(window) = window.as_ref() {
+                    log::debug!("Redraw requested by egui - request_redraw()");
                     window.request_redraw();
                 }
+                ControlFlow::Wait
             }
-        }
-        _ => (),
+            Some(when) => ControlFlow::WaitUntil(when),
+            None => ControlFlow::Wait,
+        };
     });
 }
 
 #[allow(dead_code)]
-#[cfg(target_os = "android")]
+#[cfg(all(feature = "gui", target_os = "android"))]
 #[no_mangle]
 fn android_main(android_app: AndroidApp) {
     use winit::platform::android::EventLoopBuilderExtAndroid;
@@ -194,14 +236,15 @@ fn android_main(android_app: AndroidApp) {
             ),
     );
 
+    let storage = android_app.internal_data_path();
     let event_loop = EventLoopBuilder::with_user_event()
         .with_android_app(android_app)
         .build();
-    _main(event_loop);
+    _main(event_loop, storage);
 }
 
 #[allow(dead_code)]
-#[cfg(not(target_os = "android"))]
+#[cfg(all(feature = "gui", not(target_os = "android")))]
 fn main() {
     env_logger::builder()
         .filter_level(log::LevelFilter::Warn) // Default Log Level
@@ -209,5 +252,8 @@ fn main() {
         .init();
 
     let event_loop = EventLoopBuilder::with_user_event().build();
-    _main(event_loop);
+    _main(
+        event_loop,
+        dirs::data_local_dir().map(|dir| dir.join("ktane")),
+    );
 }
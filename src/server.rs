@@ -0,0 +1,733 @@
+//! `ktane_cli serve`: answers the same questions as the command line as JSON
+//! over HTTP on localhost, for bots. `GET /schema` lists every module and the
+//! fields its `POST /<module>` endpoint takes.
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::{json, Value};
+use tiny_http::{Header, Method, Request, Response, Server};
+
+use ktane_android::solver::button::{self, Action, ButtonColor, ButtonLabel, StripColor};
+use ktane_android::solver::complicated_wires::{self, ComplicatedWire};
+use ktane_android::solver::knobs::{self, Leds};
+use ktane_android::solver::mazes::{self, Cell};
+use ktane_android::solver::memory::{self, Stage};
+use ktane_android::solver::simon_says::{self, SimonColor};
+use ktane_android::solver::wire_sequences::{self, Panel};
+use ktane_android::solver::wires::{self, WireColor};
+use ktane_android::solver::{keypad, morse_code, passwords, whos_on_first};
+
+use super::{either, serial, Error};
+
+/// A field of a module's request, as listed in the schema.
+#[derive(Serialize)]
+struct Field {
+    name: &'static str,
+    #[serde(rename = "type")]
+    kind: &'static str,
+    required: bool,
+    description: &'static str,
+}
+
+/// A module's endpoint, as listed in the schema.
+#[derive(Serialize)]
+struct Endpoint {
+    module: &'static str,
+    path: &'static str,
+    request: &'static [Field],
+    #[serde(skip)]
+    solve: fn(Value) -> Result<Value, Error>,
+}
+
+const fn field(
+    name: &'static str,
+    kind: &'static str,
+    required: bool,
+    description: &'static str,
+) -> Field {
+    Field {
+        name,
+        kind,
+        required,
+        description,
+    }
+}
+
+const SERIAL: Field = field(
+    "serial",
+    "string",
+    false,
+    "Serial number, needed when the answer depends on it.",
+);
+
+/// Every module the server answers for.
+const ENDPOINTS: [Endpoint; 12] = [
+    Endpoint {
+        module: "Wires",
+        path: "/wires",
+        request: &[
+            field(
+                "wires",
+                "array of string",
+                true,
+                "Colors from top to bottom: Red, Blue, Yellow, White or Black.",
+            ),
+            SERIAL,
+        ],
+        solve: wires,
+    },
+    Endpoint {
+        module: "Button",
+        path: "/button",
+        request: &[
+            field("color", "string", true, "Blue, White, Yellow, Red or Other."),
+            field("label", "string", true, "Abort, Detonate, Hold or Press."),
+            field("batteries", "integer", false, "Defaults to 0."),
+            field("lit_car", "boolean", false, "Defaults to false."),
+            field("lit_frk", "boolean", false, "Defaults to false."),
+            field(
+                "strip",
+                "string",
+                false,
+                "Strip color when held: Blue, White, Yellow, Red or Other.",
+            ),
+        ],
+        solve: button,
+    },
+    Endpoint {
+        module: "Keypad",
+        path: "/keypad",
+        request: &[field(
+            "symbols",
+            "array of string",
+            true,
+            "The four symbols by name or nickname.",
+        )],
+        solve: keypad,
+    },
+    Endpoint {
+        module: "Simon Says",
+        path: "/simon-says",
+        request: &[
+            field(
+                "flashes",
+                "array of string",
+                true,
+                "Flashed colors in order: Red, Blue, Green or Yellow.",
+            ),
+            SERIAL,
+            field("strikes", "integer", false, "0 to 2, defaults to 0."),
+        ],
+        solve: simon_says,
+    },
+    Endpoint {
+        module: "Who's on First",
+        path: "/whos-on-first",
+        request: &[
+            field(
+                "display",
+                "string",
+                true,
+                "The display word, \"\" when empty.",
+            ),
+            field(
+                "buttons",
+                "array of string",
+                true,
+                "The six button words, left to right and top to bottom.",
+            ),
+        ],
+        solve: whos_on_first,
+    },
+    Endpoint {
+        module: "Memory",
+        path: "/memory",
+        request: &[field(
+            "stages",
+            "array of object",
+            true,
+            "Every stage so far as {\"display\": 1-4, \"labels\": [four labels left to right]}.",
+        )],
+        solve: memory,
+    },
+    Endpoint {
+        module: "Morse Code",
+        path: "/morse-code",
+        request: &[
+            field("letters", "string", true, "The letters received so far."),
+            field(
+                "looped",
+                "boolean",
+                false,
+                "Whether the letters started anywhere in the word rather than right after the pause.",
+            ),
+        ],
+        solve: morse_code,
+    },
+    Endpoint {
+        module: "Complicated Wires",
+        path: "/complicated-wires",
+        request: &[
+            field(
+                "wires",
+                "array of object",
+                true,
+                "Every wire as {\"led\": bool, \"star\": bool, \"blue\": bool, \"red\": bool}.",
+            ),
+            SERIAL,
+            field("batteries", "integer", false, "Defaults to 0."),
+            field("parallel_port", "boolean", false, "Defaults to false."),
+        ],
+        solve: complicated_wires,
+    },
+    Endpoint {
+        module: "Wire Sequences",
+        path: "/wire-sequences",
+        request: &[field(
+            "panels",
+            "array of array",
+            true,
+            "Every panel so far as three wires from top to bottom, each {\"color\": Red, Blue or Black, \"terminal\": A, B or C} or null when missing.",
+        )],
+        solve: wire_sequences,
+    },
+    Endpoint {
+        module: "Mazes",
+        path: "/mazes",
+        request: &[
+            field(
+                "marker",
+                "object",
+                true,
+                "Either circle marker as {\"column\": 0-5, \"row\": 0-5} from the top left.",
+            ),
+            field("start", "object", true, "The white light, as for marker."),
+            field("goal", "object", true, "The red triangle, as for marker."),
+        ],
+        solve: mazes,
+    },
+    Endpoint {
+        module: "Passwords",
+        path: "/passwords",
+        request: &[field(
+            "columns",
+            "array of string",
+            true,
+            "The letters of each of the five columns, \"\" when not entered.",
+        )],
+        solve: passwords,
+    },
+    Endpoint {
+        module: "Knobs",
+        path: "/knobs",
+        request: &[field(
+            "leds",
+            "array of array",
+            true,
+            "The top and bottom rows of six LEDs from left to right, true when lit.",
+        )],
+        solve: knobs,
+    },
+];
+
+fn parse<T: DeserializeOwned>(body: Value) -> Result<T, Error> {
+    serde_json::from_value(body).map_err(|err| Error::Usage(err.to_string()))
+}
+
+fn invalid(error: impl ToString) -> Error {
+    Error::Invalid(error.to_string())
+}
+
+fn wires(body: Value) -> Result<Value, Error> {
+    #[derive(Deserialize)]
+    struct Wires {
+        wires: Vec<WireColor>,
+        serial: Option<String>,
+    }
+    let request: Wires = parse(body)?;
+    let odd = request.serial.as_deref().map(serial).transpose()?;
+    let cut = either(odd.and_then(|facts| facts.serial_odd), |odd| {
+        wires::solve(&request.wires, odd)
+            .map(|solution| solution.wire)
+            .map_err(invalid)
+    })?;
+    Ok(json!({ "cut": cut + 1 }))
+}
+
+fn button(body: Value) -> Result<Value, Error> {
+    #[derive(Deserialize)]
+    struct Button {
+        color: ButtonColor,
+        label: ButtonLabel,
+        #[serde(default)]
+        batteries: u8,
+        #[serde(default)]
+        lit_car: bool,
+        #[serde(default)]
+        lit_frk: bool,
+        strip: Option<StripColor>,
+    }
+    let request: Button = parse(body)?;
+    let action = button::solve(
+        request.color,
+        request.label,
+        request.batteries,
+        request.lit_car,
+        request.lit_frk,
+    );
+    Ok(match action {
+        Action::Press => json!({ "action": "Press" }),
+        Action::Hold => json!({
+            "action": "Hold",
+            "release_digit": request.strip.map(button::release_digit),
+        }),
+    })
+}
+
+fn keypad(body: Value) -> Result<Value, Error> {
+    #[derive(Deserialize)]
+    struct Keypad {
+        symbols: Vec<String>,
+    }
+    let request: Keypad = parse(body)?;
+    let buttons = request
+        .symbols
+        .iter()
+        .map(|name| {
+            keypad::lookup(name)
+                .ok_or_else(|| invalid(format!("no single symbol is called {name}")))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let order = keypad::solve(&buttons).map_err(invalid)?;
+    Ok(json!({ "order": order.map(|button| button.name().to_owned()) }))
+}
+
+fn simon_says(body: Value) -> Result<Value, Error> {
+    #[derive(Deserialize)]
+    struct SimonSays {
+        flashes: Vec<SimonColor>,
+        serial: Option<String>,
+        #[serde(default)]
+        strikes: u8,
+    }
+    let request: SimonSays = parse(body)?;
+    let facts = request.serial.as_deref().map(serial).transpose()?;
+    let presses = either(facts.and_then(|facts| facts.serial_vowel), |vowel| {
+        simon_says::solve(&request.flashes, vowel, request.strikes).map_err(invalid)
+    })?;
+    Ok(json!({ "presses": presses }))
+}
+
+fn whos_on_first(body: Value) -> Result<Value, Error> {
+    #[derive(Deserialize)]
+    struct WhosOnFirst {
+        display: String,
+        buttons: [String; 6],
+    }
+    let request: WhosOnFirst = parse(body)?;
+    let display = whos_on_first::display_from_abbreviation(&whos_on_first::abbreviate(
+        &request.display.to_ascii_uppercase(),
+    ))
+    .ok_or_else(|| invalid(format!("unknown display word {}", request.display)))?;
+    let mut buttons = [""; 6];
+    for (button, word) in buttons.iter_mut().zip(&request.buttons) {
+        *button = whos_on_first::button_from_abbreviation(&whos_on_first::abbreviate(
+            &word.to_ascii_uppercase(),
+        ))
+        .ok_or_else(|| invalid(format!("unknown button word {word}")))?;
+    }
+    let position = whos_on_first::solve(display, &buttons).map_err(invalid)?;
+    Ok(json!({
+        "position": position.as_ref(),
+        "word": buttons[position.index()],
+    }))
+}
+
+fn memory(body: Value) -> Result<Value, Error> {
+    #[derive(Deserialize)]
+    struct Entered {
+        display: u8,
+        labels: [u8; 4],
+    }
+    #[derive(Deserialize)]
+    struct Memory {
+        stages: Vec<Entered>,
+    }
+    let request: Memory = parse(body)?;
+    let mut history: Vec<Stage> = vec![];
+    let mut pressed = None;
+    for stage in &request.stages {
+        pressed = Some(memory::solve(&history, stage.display, stage.labels).map_err(invalid)?);
+        history.extend(pressed.map(Stage::from));
+    }
+    let pressed = pressed.ok_or_else(|| Error::Usage("expected at least one stage".to_owned()))?;
+    Ok(json!({ "position": pressed.position, "label": pressed.label }))
+}
+
+fn morse_code(body: Value) -> Result<Value, Error> {
+    #[derive(Deserialize)]
+    struct MorseCode {
+        letters: String,
+        #[serde(default)]
+        looped: bool,
+    }
+    let request: MorseCode = parse(body)?;
+    let letters = request.letters.to_uppercase();
+    let words = if request.looped {
+        morse_code::solve_looped(&letters)
+    } else {
+        morse_code::solve(&letters)
+    };
+    let words: Vec<_> = words
+        .iter()
+        .map(|word| {
+            json!({
+                "word": word.word,
+                "frequency_khz": word.frequency,
+                "right_presses": word.presses(),
+            })
+        })
+        .collect();
+    Ok(json!({ "words": words }))
+}
+
+fn complicated_wires(body: Value) -> Result<Value, Error> {
+    #[derive(Deserialize)]
+    struct ComplicatedWires {
+        wires: Vec<ComplicatedWire>,
+        serial: Option<String>,
+        #[serde(default)]
+        batteries: u8,
+        #[serde(default)]
+        parallel_port: bool,
+    }
+    let request: ComplicatedWires = parse(body)?;
+    let facts = request.serial.as_deref().map(serial).transpose()?;
+    let cut = request
+        .wires
+        .iter()
+        .map(|wire| {
+            either(facts.and_then(|facts| facts.serial_odd), |odd| {
+                Ok(complicated_wires::solve(
+                    *wire,
+                    !odd,
+                    request.parallel_port,
+                    request.batteries,
+                ))
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(json!({ "cut": cut }))
+}
+
+fn wire_sequences(body: Value) -> Result<Value, Error> {
+    #[derive(Deserialize)]
+    struct WireSequences {
+        panels: Vec<Panel>,
+    }
+    let request: WireSequences = parse(body)?;
+    if request.panels.is_empty() {
+        return Err(Error::Usage("expected at least one panel".to_owned()));
+    }
+    let cut = wire_sequences::solve_panel(&request.panels).map_err(invalid)?;
+    Ok(json!({ "cut": cut }))
+}
+
+fn mazes(body: Value) -> Result<Value, Error> {
+    #[derive(Deserialize)]
+    struct Mazes {
+        marker: Cell,
+        start: Cell,
+        goal: Cell,
+    }
+    let request: Mazes = parse(body)?;
+    let maze = mazes::identify(request.marker).map_err(invalid)?;
+    let moves = mazes::solve(&mazes::MAZES[maze], request.start, request.goal).map_err(invalid)?;
+    Ok(json!({
+        "maze": maze + 1,
+        "moves": moves.iter().map(|direction| direction.as_ref()).collect::<Vec<_>>(),
+    }))
+}
+
+fn knobs(body: Value) -> Result<Value, Error> {
+    #[derive(Deserialize)]
+    struct Knobs {
+        leds: Leds,
+    }
+    let request: Knobs = parse(body)?;
+    let position = knobs::solve(&request.leds).map_err(invalid)?;
+    Ok(json!({ "position": position.as_ref() }))
+}
+
+fn passwords(body: Value) -> Result<Value, Error> {
+    #[derive(Deserialize)]
+    struct Passwords {
+        columns: [String; 5],
+    }
+    let request: Passwords = parse(body)?;
+    Ok(json!({
+        "words": passwords::solve(&request.columns),
+        "best_column": passwords::best_column(&request.columns).map(|column| column + 1),
+    }))
+}
+
+/// The status and body to answer `request` with.
+fn answer(request: &mut Request) -> (u16, Value) {
+    // Query strings are not used, but should not hide the path.
+    let url = request.url();
+    let path = url.split_once('?').map_or(url, |(path, _)| path);
+    if *request.method() == Method::Get && path == "/schema" {
+        return (200, json!({ "modules": ENDPOINTS }));
+    }
+    let Some(endpoint) = ENDPOINTS.iter().find(|endpoint| endpoint.path == path) else {
+        return (404, json!({ "error": format!("no module at {path}") }));
+    };
+    if *request.method() != Method::Post {
+        return (405, json!({ "error": "modules take POST requests" }));
+    }
+    let body = match serde_json::from_reader(request.as_reader()) {
+        Ok(body) => body,
+        Err(err) => return (400, json!({ "error": err.to_string() })),
+    };
+    match (endpoint.solve)(body) {
+        Ok(answer) => (200, answer),
+        Err(Error::Usage(message)) => (400, json!({ "error": message })),
+        Err(Error::Invalid(message)) => (422, json!({ "error": message })),
+    }
+}
+
+/// Serves requests on localhost until the process is stopped.
+pub fn serve(port: u16) -> Result<(), String> {
+    let server = Server::http(("127.0.0.1", port)).map_err(|err| err.to_string())?;
+    eprintln!("Listening on http://127.0.0.1:{port}, see /schema");
+    let content_type: Header = "Content-Type: application/json"
+        .parse()
+        .expect("the header is valid");
+    for mut request in server.incoming_requests() {
+        let (status, body) = answer(&mut request);
+        let response = Response::from_string(body.to_string())
+            .with_status_code(status)
+            .with_header(content_type.clone());
+        if let Err(err) = request.respond(response) {
+            eprintln!("error: failed to respond: {err}");
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use tiny_http::TestRequest;
+
+    use super::*;
+
+    fn is_usage(result: Result<Value, Error>) -> bool {
+        matches!(result, Err(Error::Usage(_)))
+    }
+
+    fn is_invalid(result: Result<Value, Error>) -> bool {
+        matches!(result, Err(Error::Invalid(_)))
+    }
+
+    fn request(method: Method, path: &str, body: &'static str) -> (u16, Value) {
+        let mut request = TestRequest::new()
+            .with_method(method)
+            .with_path(path)
+            .with_body(body)
+            .into();
+        answer(&mut request)
+    }
+
+    #[test]
+    fn routing() {
+        let (status, schema) = request(Method::Get, "/schema", "");
+        assert_eq!(status, 200);
+        assert_eq!(schema["modules"].as_array().map(Vec::len), Some(12));
+        let wires = r#"{"wires": ["Red", "Blue", "Blue", "Yellow", "White"]}"#;
+        assert_eq!(
+            request(Method::Post, "/wires?source=bot", wires),
+            (200, json!({ "cut": 2 }))
+        );
+        assert_eq!(request(Method::Get, "/wires", wires).0, 405);
+        assert_eq!(request(Method::Post, "/needy", wires).0, 404);
+        assert_eq!(request(Method::Post, "/wires", "{").0, 400);
+        assert_eq!(request(Method::Post, "/wires", r#"{"wire": []}"#).0, 400);
+        assert_eq!(request(Method::Post, "/wires", r#"{"wires": []}"#).0, 422);
+    }
+
+    #[test]
+    fn wires() {
+        let body = json!({ "wires": ["Red", "Red", "Blue", "Yellow"] });
+        assert!(is_usage(super::wires(body.clone())));
+        let mut odd = body;
+        odd["serial"] = json!("AB3DE5");
+        assert_eq!(super::wires(odd).ok(), Some(json!({ "cut": 2 })));
+        assert!(is_invalid(super::wires(json!({
+            "wires": ["Red"],
+            "serial": "AB3DE5",
+        }))));
+    }
+
+    #[test]
+    fn button() {
+        assert_eq!(
+            super::button(json!({ "color": "Other", "label": "Hold", "strip": "Yellow" })).ok(),
+            Some(json!({ "action": "Hold", "release_digit": 5 }))
+        );
+        assert_eq!(
+            super::button(json!({ "color": "Red", "label": "Hold" })).ok(),
+            Some(json!({ "action": "Press" }))
+        );
+        assert!(is_usage(super::button(
+            json!({ "color": "Black", "label": "Hold" })
+        )));
+    }
+
+    #[test]
+    fn keypad() {
+        assert_eq!(
+            super::keypad(json!({ "symbols": ["lightning", "balloon", "lambda", "at"] })).ok(),
+            Some(json!({ "order": ["O", "A", "Lambda", "N"] }))
+        );
+        assert!(is_invalid(super::keypad(
+            json!({ "symbols": ["omega", "psi", "ae", "balloon"] })
+        )));
+    }
+
+    #[test]
+    fn simon_says() {
+        assert_eq!(
+            super::simon_says(json!({
+                "flashes": ["Red", "Blue", "Green"],
+                "serial": "AB3DE4",
+                "strikes": 1,
+            }))
+            .ok(),
+            Some(json!({ "presses": ["Yellow", "Green", "Blue"] }))
+        );
+        assert!(is_usage(super::simon_says(json!({ "flashes": ["Blue"] }))));
+    }
+
+    #[test]
+    fn whos_on_first() {
+        assert_eq!(
+            super::whos_on_first(json!({
+                "display": "yes",
+                "buttons": ["ready", "no", "yes", "wait", "blank", "first"],
+            }))
+            .ok(),
+            Some(json!({ "position": "BottomRight", "word": "FIRST" }))
+        );
+        assert!(is_invalid(super::whos_on_first(json!({
+            "display": "maybe",
+            "buttons": ["READY", "NO", "YES", "WAIT", "BLANK", "FIRST"],
+        }))));
+    }
+
+    #[test]
+    fn memory() {
+        assert_eq!(
+            super::memory(json!({
+                "stages": [
+                    { "display": 3, "labels": [2, 4, 1, 3] },
+                    { "display": 1, "labels": [4, 3, 1, 2] },
+                ],
+            }))
+            .ok(),
+            Some(json!({ "position": 1, "label": 4 }))
+        );
+        assert!(is_usage(super::memory(json!({ "stages": [] }))));
+        assert!(is_invalid(super::memory(json!({
+            "stages": [{ "display": 5, "labels": [1, 2, 3, 4] }],
+        }))));
+    }
+
+    #[test]
+    fn morse_code() {
+        assert_eq!(
+            super::morse_code(json!({ "letters": "she" })).ok(),
+            Some(json!({
+                "words": [{ "word": "SHELL", "frequency_khz": 3505, "right_presses": 0 }],
+            }))
+        );
+    }
+
+    #[test]
+    fn complicated_wires() {
+        let plain = json!({ "led": false, "star": false, "blue": false, "red": false });
+        let red = json!({ "led": false, "star": false, "blue": false, "red": true });
+        let lit_red = json!({ "led": true, "star": false, "blue": false, "red": true });
+        assert_eq!(
+            super::complicated_wires(json!({ "wires": [plain, lit_red], "batteries": 2 })).ok(),
+            Some(json!({ "cut": [true, true] }))
+        );
+        assert_eq!(
+            super::complicated_wires(json!({ "wires": [red.clone()], "serial": "AB3DE4" })).ok(),
+            Some(json!({ "cut": [true] }))
+        );
+        assert!(is_usage(super::complicated_wires(
+            json!({ "wires": [red] })
+        )));
+    }
+
+    #[test]
+    fn wire_sequences() {
+        assert_eq!(
+            super::wire_sequences(json!({
+                "panels": [
+                    [{ "color": "Red", "terminal": "C" }, null, { "color": "Blue", "terminal": "A" }],
+                    [null, { "color": "Red", "terminal": "A" }, null],
+                ],
+            }))
+            .ok(),
+            Some(json!({ "cut": [null, false, null] }))
+        );
+        assert!(is_usage(super::wire_sequences(json!({ "panels": [] }))));
+    }
+
+    #[test]
+    fn mazes() {
+        let answer = super::mazes(json!({
+            "marker": { "column": 0, "row": 1 },
+            "start": { "column": 0, "row": 0 },
+            "goal": { "column": 2, "row": 0 },
+        }))
+        .ok();
+        assert_eq!(
+            answer,
+            Some(json!({ "maze": 1, "moves": ["Right", "Right"] }))
+        );
+        assert!(is_invalid(super::mazes(json!({
+            "marker": { "column": 0, "row": 1 },
+            "start": { "column": 0, "row": 0 },
+            "goal": { "column": 6, "row": 0 },
+        }))));
+    }
+
+    #[test]
+    fn knobs() {
+        assert_eq!(
+            super::knobs(json!({
+                "leds": [
+                    [false, false, true, false, true, true],
+                    [true, true, true, true, false, true],
+                ],
+            }))
+            .ok(),
+            Some(json!({ "position": "Up" }))
+        );
+        assert!(is_invalid(super::knobs(
+            json!({ "leds": [[false, false, false, false, false, false], [false, false, false, false, false, false]] })
+        )));
+    }
+
+    #[test]
+    fn passwords() {
+        assert_eq!(
+            super::passwords(json!({ "columns": ["WPS", "HLP", "", "", ""] })).ok(),
+            Some(json!({
+                "words": ["PLACE", "PLANT", "SPELL", "WHERE", "WHICH"],
+                "best_column": 4,
+            }))
+        );
+    }
+}
@@ -0,0 +1,31 @@
+//! Rule logic for every vanilla module, independent of the user interface.
+//!
+//! Each submodule takes typed inputs describing what the defuser sees and
+//! returns typed answers, so the same rules can be used by the app and by
+//! other tools.
+
+pub mod button;
+pub mod complicated_wires;
+pub mod edgework;
+pub mod keypad;
+pub mod knobs;
+pub mod mazes;
+pub mod memory;
+pub mod morse_code;
+pub mod passwords;
+pub mod simon_says;
+pub mod timer;
+pub mod whos_on_first;
+pub mod wire_sequences;
+pub mod wires;
+
+/// "1st", "2nd" and so on for the small numbers modules count up to.
+pub fn ordinal(n: usize) -> String {
+    let suffix = match n {
+        1 => "st",
+        2 => "nd",
+        3 => "rd",
+        _ => "th",
+    };
+    format!("{n}{suffix}")
+}
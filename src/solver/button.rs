@@ -0,0 +1,98 @@
+use serde::{Deserialize, Serialize};
+use strum_macros::{AsRefStr, EnumIter};
+
+#[derive(EnumIter, AsRefStr, Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum ButtonColor {
+    Blue,
+    White,
+    Yellow,
+    Red,
+    Other,
+}
+
+#[derive(EnumIter, AsRefStr, Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum ButtonLabel {
+    Abort,
+    Detonate,
+    Hold,
+    Press,
+}
+
+#[derive(AsRefStr, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Action {
+    /// Press and immediately release the button.
+    Press,
+    /// Hold the button and release it according to the strip color.
+    Hold,
+}
+
+/// The color of the strip that lights up while the button is held.
+#[derive(EnumIter, AsRefStr, Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum StripColor {
+    Blue,
+    White,
+    Yellow,
+    Red,
+    Other,
+}
+
+/// Decides whether to press or hold the button.
+pub fn solve(
+    color: ButtonColor,
+    label: ButtonLabel,
+    batteries: u8,
+    lit_car: bool,
+    lit_frk: bool,
+) -> Action {
+    if color == ButtonColor::Blue && label == ButtonLabel::Abort {
+        Action::Hold
+    } else if batteries > 1 && label == ButtonLabel::Detonate {
+        Action::Press
+    } else if color == ButtonColor::White && lit_car {
+        Action::Hold
+    } else if batteries > 2 && lit_frk {
+        Action::Press
+    } else if color == ButtonColor::Yellow {
+        Action::Hold
+    } else if color == ButtonColor::Red && label == ButtonLabel::Hold {
+        Action::Press
+    } else {
+        Action::Hold
+    }
+}
+
+/// The digit that must appear anywhere on the timer to release a held button.
+pub fn release_digit(strip: StripColor) -> u8 {
+    match strip {
+        StripColor::Blue => 4,
+        StripColor::Yellow => 5,
+        _ => 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rules_in_order() {
+        use ButtonColor::*;
+        use ButtonLabel::*;
+        assert_eq!(solve(Blue, Abort, 3, false, true), Action::Hold);
+        assert_eq!(solve(Blue, Detonate, 2, false, false), Action::Press);
+        assert_eq!(solve(White, Abort, 0, true, false), Action::Hold);
+        assert_eq!(solve(White, Abort, 3, false, true), Action::Press);
+        assert_eq!(solve(Yellow, Press, 0, false, false), Action::Hold);
+        assert_eq!(solve(Red, Hold, 0, false, false), Action::Press);
+        assert_eq!(solve(Red, Press, 0, false, false), Action::Hold);
+        assert_eq!(solve(Other, Detonate, 1, false, false), Action::Hold);
+    }
+
+    #[test]
+    fn release() {
+        assert_eq!(release_digit(StripColor::Blue), 4);
+        assert_eq!(release_digit(StripColor::White), 1);
+        assert_eq!(release_digit(StripColor::Yellow), 5);
+        assert_eq!(release_digit(StripColor::Other), 1);
+    }
+}
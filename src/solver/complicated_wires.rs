@@ -0,0 +1,119 @@
+use serde::{Deserialize, Serialize};
+use strum_macros::AsRefStr;
+
+/// The attributes of a single wire.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+pub struct ComplicatedWire {
+    pub led: bool,
+    pub star: bool,
+    pub blue: bool,
+    pub red: bool,
+}
+
+/// When a wire should be cut.
+#[derive(AsRefStr, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Condition {
+    Always,
+    Never,
+    SerialEven,
+    ParallelPort,
+    TwoBatteries,
+}
+
+impl Condition {
+    pub fn name(&self) -> &str {
+        match self {
+            Condition::Always => "ALWAYS",
+            Condition::Never => "NEVER",
+            Condition::SerialEven => "SERIAL NUMBER ENDS EVEN",
+            Condition::ParallelPort => "PARALLEL PORT",
+            Condition::TwoBatteries => "2+ BATTERIES",
+        }
+    }
+}
+
+/// Indexed by LED + 2 * star + 4 * blue + 8 * red.
+const TABLE: [Condition; 16] = [
+    Condition::Always,
+    Condition::Never,
+    Condition::Always,
+    Condition::TwoBatteries,
+    Condition::SerialEven,
+    Condition::ParallelPort,
+    Condition::Never,
+    Condition::ParallelPort,
+    Condition::SerialEven,
+    Condition::TwoBatteries,
+    Condition::Always,
+    Condition::TwoBatteries,
+    Condition::SerialEven,
+    Condition::SerialEven,
+    Condition::ParallelPort,
+    Condition::Never,
+];
+
+/// The condition under which a wire should be cut.
+pub fn condition(wire: ComplicatedWire) -> Condition {
+    TABLE[wire.led as usize
+        + 2 * wire.star as usize
+        + 4 * wire.blue as usize
+        + 8 * wire.red as usize]
+}
+
+/// Whether a wire should be cut on this bomb.
+pub fn solve(wire: ComplicatedWire, serial_even: bool, parallel_port: bool, batteries: u8) -> bool {
+    match condition(wire) {
+        Condition::Always => true,
+        Condition::Never => false,
+        Condition::SerialEven => serial_even,
+        Condition::ParallelPort => parallel_port,
+        Condition::TwoBatteries => batteries >= 2,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conditions() {
+        let wire = ComplicatedWire::default();
+        assert_eq!(condition(wire), Condition::Always);
+        let wire = ComplicatedWire {
+            led: true,
+            star: true,
+            blue: true,
+            red: true,
+        };
+        assert_eq!(condition(wire), Condition::Never);
+        let wire = ComplicatedWire {
+            led: true,
+            blue: true,
+            ..Default::default()
+        };
+        assert_eq!(condition(wire), Condition::ParallelPort);
+        let wire = ComplicatedWire {
+            led: true,
+            red: true,
+            ..Default::default()
+        };
+        assert_eq!(condition(wire), Condition::TwoBatteries);
+    }
+
+    #[test]
+    fn cuts() {
+        let wire = ComplicatedWire {
+            red: true,
+            ..Default::default()
+        };
+        assert!(solve(wire, true, false, 0));
+        assert!(!solve(wire, false, true, 3));
+        let wire = ComplicatedWire {
+            led: true,
+            star: true,
+            ..Default::default()
+        };
+        assert!(solve(wire, false, false, 2));
+        assert!(!solve(wire, true, true, 1));
+    }
+}
@@ -0,0 +1,165 @@
+use serde::{Deserialize, Serialize};
+use strum_macros::{AsRefStr, EnumIter};
+
+#[derive(EnumIter, AsRefStr, Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum IndicatorLabel {
+    SND,
+    CLR,
+    CAR,
+    IND,
+    FRQ,
+    SIG,
+    NSA,
+    MSA,
+    TRN,
+    BOB,
+    FRK,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct Indicator {
+    pub label: IndicatorLabel,
+    pub lit: bool,
+}
+
+#[derive(EnumIter, AsRefStr, Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum Port {
+    DviD,
+    Parallel,
+    Ps2,
+    Rj45,
+    Serial,
+    StereoRca,
+}
+
+impl Port {
+    pub fn name(&self) -> &str {
+        match self {
+            Port::DviD => "DVI-D",
+            Port::Ps2 => "PS/2",
+            Port::Rj45 => "RJ-45",
+            Port::StereoRca => "Stereo RCA",
+            p => p.as_ref(),
+        }
+    }
+}
+
+/// Everything on the outside of the bomb casing.
+#[derive(Clone, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+pub struct Edgework {
+    /// The serial number, or empty if it has not been entered.
+    pub serial: String,
+    pub batteries: u8,
+    pub battery_holders: u8,
+    pub indicators: Vec<Indicator>,
+    pub ports: Vec<Port>,
+}
+
+impl Edgework {
+    /// Whether the serial number looks like a real one: six letters and
+    /// digits ending in a digit.
+    pub fn serial_valid(&self) -> bool {
+        self.serial.len() == 6
+            && self.serial.chars().all(|c| c.is_ascii_alphanumeric())
+            && self.serial.ends_with(|c: char| c.is_ascii_digit())
+    }
+
+    /// The last digit of the serial number.
+    pub fn last_digit(&self) -> Option<u8> {
+        self.serial
+            .chars()
+            .rev()
+            .find_map(|c| c.to_digit(10))
+            .map(|d| d as u8)
+    }
+
+    pub fn has_indicator(&self, label: IndicatorLabel, lit: bool) -> bool {
+        self.indicators.contains(&Indicator { label, lit })
+    }
+
+    pub fn has_port(&self, port: Port) -> bool {
+        self.ports.contains(&port)
+    }
+
+    /// Derives the facts modules ask about.
+    pub fn facts(&self) -> Facts {
+        let serial = (!self.serial.is_empty()).then_some(&self.serial);
+        Facts {
+            serial_vowel: serial.map(|serial| {
+                serial
+                    .chars()
+                    .any(|c| "AEIOU".contains(c.to_ascii_uppercase()))
+            }),
+            serial_odd: self.last_digit().map(|digit| digit % 2 == 1),
+            batteries: self.batteries,
+            lit_car: self.has_indicator(IndicatorLabel::CAR, true),
+            lit_frk: self.has_indicator(IndicatorLabel::FRK, true),
+            parallel_port: self.has_port(Port::Parallel),
+        }
+    }
+}
+
+/// Facts about the bomb that the vanilla modules depend on. Facts from the
+/// serial number are `None` until it has been entered.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct Facts {
+    pub serial_vowel: Option<bool>,
+    pub serial_odd: Option<bool>,
+    pub batteries: u8,
+    pub lit_car: bool,
+    pub lit_frk: bool,
+    pub parallel_port: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn facts() {
+        let edgework = Edgework {
+            serial: "AB3DE4".to_owned(),
+            batteries: 3,
+            battery_holders: 2,
+            indicators: vec![
+                Indicator {
+                    label: IndicatorLabel::CAR,
+                    lit: true,
+                },
+                Indicator {
+                    label: IndicatorLabel::FRK,
+                    lit: false,
+                },
+            ],
+            ports: vec![Port::Parallel],
+        };
+        assert!(edgework.serial_valid());
+        assert_eq!(
+            edgework.facts(),
+            Facts {
+                serial_vowel: Some(true),
+                serial_odd: Some(false),
+                batteries: 3,
+                lit_car: true,
+                lit_frk: false,
+                parallel_port: true,
+            }
+        );
+    }
+
+    #[test]
+    fn unknown_serial() {
+        let edgework = Edgework::default();
+        assert!(!edgework.serial_valid());
+        assert_eq!(edgework.facts().serial_vowel, None);
+        assert_eq!(edgework.facts().serial_odd, None);
+
+        let edgework = Edgework {
+            serial: "XK7".to_owned(),
+            ..Default::default()
+        };
+        assert!(!edgework.serial_valid());
+        assert_eq!(edgework.facts().serial_vowel, Some(false));
+        assert_eq!(edgework.facts().serial_odd, Some(true));
+    }
+}
@@ -0,0 +1,364 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+use strum_macros::{AsRefStr, EnumIter};
+
+#[derive(
+    EnumIter,
+    AsRefStr,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Debug,
+    Serialize,
+    Deserialize,
+)]
+pub enum KeypadButton {
+    O,
+    A,
+    Lambda,
+    N,
+    Person,
+    H,
+    AntiC,
+    Euro,
+    Q,
+    EmptyStar,
+    Question,
+    Copyright,
+    W,
+    X,
+    R,
+    N6,
+    Paragraph,
+    B,
+    Smile,
+    Trident,
+    C,
+    Snake,
+    FilledStar,
+    Puzzle,
+    AE,
+    Yot,
+    Omega,
+}
+
+impl KeypadButton {
+    pub fn name(&self) -> &str {
+        match self {
+            KeypadButton::AntiC => "Anti-C",
+            KeypadButton::EmptyStar => "Empty Star",
+            KeypadButton::N6 => "6",
+            KeypadButton::FilledStar => "Filled Star",
+            KeypadButton::Yot => "Yot",
+            k => k.as_ref(),
+        }
+    }
+
+    /// The Unicode character that looks most like the symbol.
+    pub fn glyph(&self) -> char {
+        match self {
+            KeypadButton::O => '\u{3D8}',
+            KeypadButton::A => '\u{466}',
+            KeypadButton::Lambda => '\u{19B}',
+            KeypadButton::N => '\u{3DE}',
+            KeypadButton::Person => '\u{46C}',
+            KeypadButton::H => '\u{3D7}',
+            KeypadButton::AntiC => '\u{3FF}',
+            KeypadButton::Euro => '\u{4EC}',
+            KeypadButton::Q => '\u{4A8}',
+            KeypadButton::EmptyStar => '\u{2606}',
+            KeypadButton::Question => '\u{BF}',
+            KeypadButton::Copyright => '\u{A9}',
+            KeypadButton::W => '\u{47C}',
+            KeypadButton::X => '\u{496}',
+            KeypadButton::R => '\u{506}',
+            KeypadButton::N6 => '\u{431}',
+            KeypadButton::Paragraph => '\u{B6}',
+            KeypadButton::B => '\u{462}',
+            KeypadButton::Smile => '\u{67C}',
+            KeypadButton::Trident => '\u{3A8}',
+            KeypadButton::C => '\u{3FE}',
+            KeypadButton::Snake => '\u{46E}',
+            KeypadButton::FilledStar => '\u{2605}',
+            KeypadButton::Puzzle => '\u{482}',
+            KeypadButton::AE => '\u{E6}',
+            KeypadButton::Yot => '\u{48A}',
+            KeypadButton::Omega => '\u{3A9}',
+        }
+    }
+
+    /// Other names teams use for the symbol.
+    pub fn nicknames(&self) -> &'static [&'static str] {
+        match self {
+            KeypadButton::O => &["balloon", "lollipop", "koppa"],
+            KeypadButton::A => &["at", "tent", "yus"],
+            KeypadButton::Lambda => &["hooked lambda", "lambda with line"],
+            KeypadButton::N => &["lightning", "squiggly n", "bolt"],
+            KeypadButton::Person => &["squid", "squid knife", "alien"],
+            KeypadButton::H => &["hook n", "curly h", "kai"],
+            KeypadButton::AntiC => &["backwards c", "reverse c", "dotted backwards c"],
+            KeypadButton::Euro => &["backwards e", "e with dots"],
+            KeypadButton::Q => &["curly q", "cursive", "ghost"],
+            KeypadButton::EmptyStar => &["hollow star", "white star", "outline star"],
+            KeypadButton::Question => &["upside down question", "question mark"],
+            KeypadButton::Copyright => &["c in circle"],
+            KeypadButton::W => &["pumpkin", "butt", "omega with titlo"],
+            KeypadButton::X => &["double k", "kk", "zhe"],
+            KeypadButton::R => &["melted 3", "3 with tail"],
+            KeypadButton::N6 => &["six", "be"],
+            KeypadButton::Paragraph => &["pilcrow", "backwards p"],
+            KeypadButton::B => &["bt", "b with line", "yat"],
+            KeypadButton::Smile => &["smiley", "smiley face", "face"],
+            KeypadButton::Trident => &["pitchfork", "psi", "candelabra"],
+            KeypadButton::C => &["c with dot", "dotted c"],
+            KeypadButton::Snake => &["3 with hat", "ksi", "squiggle"],
+            KeypadButton::FilledStar => &["black star", "solid star"],
+            KeypadButton::Puzzle => &["train tracks", "railroad", "not equal"],
+            KeypadButton::AE => &["ash"],
+            KeypadButton::Yot => &["n with hat", "backwards n", "i with hat"],
+            KeypadButton::Omega => &["horseshoe", "ohm"],
+        }
+    }
+}
+
+/// The symbol called `name`, ignoring case, or the only symbol whose name or
+/// a nickname contains it.
+pub fn lookup(name: &str) -> Option<KeypadButton> {
+    let name = name.trim().to_lowercase();
+    KeypadButton::iter()
+        .find(|button| {
+            button.name().to_lowercase() == name || button.nicknames().contains(&name.as_str())
+        })
+        .or_else(|| match search(&name)[..] {
+            [button] => Some(button),
+            _ => None,
+        })
+}
+
+/// The symbols whose name or a nickname contains `query`, ignoring case.
+pub fn search(query: &str) -> Vec<KeypadButton> {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return vec![];
+    }
+    KeypadButton::iter()
+        .filter(|button| {
+            button.name().to_lowercase().contains(&query)
+                || button
+                    .nicknames()
+                    .iter()
+                    .any(|nickname| nickname.contains(&query))
+        })
+        .collect()
+}
+
+/// The six symbol columns from the manual, each read top to bottom.
+pub const COLUMNS: [[KeypadButton; 7]; 6] = [
+    [
+        KeypadButton::O,
+        KeypadButton::A,
+        KeypadButton::Lambda,
+        KeypadButton::N,
+        KeypadButton::Person,
+        KeypadButton::H,
+        KeypadButton::AntiC,
+    ],
+    [
+        KeypadButton::Euro,
+        KeypadButton::O,
+        KeypadButton::AntiC,
+        KeypadButton::Q,
+        KeypadButton::EmptyStar,
+        KeypadButton::H,
+        KeypadButton::Question,
+    ],
+    [
+        KeypadButton::Copyright,
+        KeypadButton::W,
+        KeypadButton::Q,
+        KeypadButton::X,
+        KeypadButton::R,
+        KeypadButton::Lambda,
+        KeypadButton::EmptyStar,
+    ],
+    [
+        KeypadButton::N6,
+        KeypadButton::Paragraph,
+        KeypadButton::B,
+        KeypadButton::Person,
+        KeypadButton::X,
+        KeypadButton::Question,
+        KeypadButton::Smile,
+    ],
+    [
+        KeypadButton::Trident,
+        KeypadButton::Smile,
+        KeypadButton::B,
+        KeypadButton::C,
+        KeypadButton::Paragraph,
+        KeypadButton::Snake,
+        KeypadButton::FilledStar,
+    ],
+    [
+        KeypadButton::N6,
+        KeypadButton::Euro,
+        KeypadButton::Puzzle,
+        KeypadButton::AE,
+        KeypadButton::Trident,
+        KeypadButton::Yot,
+        KeypadButton::Omega,
+    ],
+];
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum KeypadError {
+    /// The keypad always has exactly four symbols.
+    WrongCount(usize),
+    Duplicate(KeypadButton),
+    /// No single column contains all four symbols.
+    NoColumn,
+}
+
+impl fmt::Display for KeypadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KeypadError::WrongCount(count) => write!(f, "expected 4 symbols, got {count}"),
+            KeypadError::Duplicate(button) => write!(f, "{} was entered twice", button.name()),
+            KeypadError::NoColumn => write!(f, "no column contains these symbols"),
+        }
+    }
+}
+
+impl std::error::Error for KeypadError {}
+
+/// Indices into [`COLUMNS`] of the columns containing every symbol picked so
+/// far.
+pub fn possible_columns(buttons: &[KeypadButton]) -> Vec<usize> {
+    COLUMNS
+        .iter()
+        .enumerate()
+        .filter(|(_, column)| buttons.iter().all(|button| column.contains(button)))
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// Unpicked symbols in some but not all of the possible columns, so finding
+/// out whether the keypad has one rules columns out.
+pub fn disambiguating(buttons: &[KeypadButton]) -> Vec<KeypadButton> {
+    let columns = possible_columns(buttons);
+    let mut symbols: Vec<KeypadButton> = columns
+        .iter()
+        .flat_map(|i| COLUMNS[*i])
+        .filter(|symbol| !buttons.contains(symbol))
+        .filter(|symbol| {
+            let count = columns
+                .iter()
+                .filter(|i| COLUMNS[**i].contains(symbol))
+                .count();
+            count < columns.len()
+        })
+        .collect();
+    symbols.sort();
+    symbols.dedup();
+    symbols
+}
+
+/// Orders the four symbols on the keypad in the order they must be pressed.
+pub fn solve(buttons: &[KeypadButton]) -> Result<[KeypadButton; 4], KeypadError> {
+    if buttons.len() != 4 {
+        return Err(KeypadError::WrongCount(buttons.len()));
+    }
+    for (i, button) in buttons.iter().enumerate() {
+        if buttons[..i].contains(button) {
+            return Err(KeypadError::Duplicate(*button));
+        }
+    }
+
+    let column = COLUMNS
+        .iter()
+        .find(|column| buttons.iter().all(|button| column.contains(button)))
+        .ok_or(KeypadError::NoColumn)?;
+    let mut order = [buttons[0], buttons[1], buttons[2], buttons[3]];
+    order.sort_by_key(|button| column.iter().position(|b| b == button));
+    Ok(order)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::KeypadButton::*;
+    use super::*;
+
+    #[test]
+    fn orders_by_column() {
+        assert_eq!(solve(&[AntiC, Lambda, O, H]), Ok([O, Lambda, H, AntiC]));
+        assert_eq!(solve(&[Omega, N6, Yot, AE]), Ok([N6, AE, Yot, Omega]));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(solve(&[O, A, Lambda]), Err(KeypadError::WrongCount(3)));
+        assert_eq!(solve(&[O, A, O, N]), Err(KeypadError::Duplicate(O)));
+        assert_eq!(solve(&[O, A, Lambda, Omega]), Err(KeypadError::NoColumn));
+    }
+
+    #[test]
+    fn searches_nicknames() {
+        assert_eq!(search("squid"), vec![Person]);
+        assert_eq!(search("Pitchfork"), vec![Trident]);
+        assert_eq!(search("backwards c"), vec![AntiC]);
+        assert_eq!(search("smiley"), vec![Smile]);
+        assert_eq!(search("empty star"), vec![EmptyStar]);
+        assert!(search("  ").is_empty());
+        assert!(search("star").contains(&FilledStar));
+    }
+
+    #[test]
+    fn looks_up_names() {
+        assert_eq!(lookup("omega"), Some(Omega));
+        assert_eq!(lookup("psi"), Some(Trident));
+        assert_eq!(lookup("AE"), Some(AE));
+        assert_eq!(lookup("c"), Some(C));
+        assert_eq!(lookup("pilc"), Some(Paragraph));
+        assert_eq!(lookup("squi"), None);
+        assert_eq!(lookup("star"), None);
+    }
+
+    #[test]
+    fn glyphs_are_distinct() {
+        for button in KeypadButton::iter() {
+            assert_eq!(
+                KeypadButton::iter()
+                    .filter(|b| b.glyph() == button.glyph())
+                    .count(),
+                1,
+                "{}",
+                button.name()
+            );
+        }
+    }
+
+    #[test]
+    fn narrows_columns() {
+        assert_eq!(possible_columns(&[]), vec![0, 1, 2, 3, 4, 5]);
+        assert_eq!(possible_columns(&[Omega]), vec![5]);
+        assert_eq!(possible_columns(&[O, Omega]), Vec::<usize>::new());
+        assert!(disambiguating(&[Omega]).is_empty());
+        assert!(disambiguating(&[O, Omega]).is_empty());
+
+        let columns = possible_columns(&[Question]);
+        assert!(columns.len() > 1);
+        for symbol in disambiguating(&[Question]) {
+            let count = columns
+                .iter()
+                .filter(|i| COLUMNS[**i].contains(&symbol))
+                .count();
+            assert!(count > 0 && count < columns.len());
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use std::fmt;
+
+use strum_macros::{AsRefStr, EnumIter};
+
+/// Knob positions relative to the "UP" label on the module.
+#[derive(EnumIter, AsRefStr, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum KnobPosition {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+/// The twelve LEDs as two rows of six, `true` when lit.
+pub type Leds = [[bool; 6]; 2];
+
+const fn leds(top: [u8; 6], bottom: [u8; 6]) -> Leds {
+    let mut leds = [[false; 6]; 2];
+    let mut i = 0;
+    while i < 6 {
+        leds[0][i] = top[i] == 1;
+        leds[1][i] = bottom[i] == 1;
+        i += 1;
+    }
+    leds
+}
+
+/// Every LED pattern from the manual and the position it calls for.
+pub const PATTERNS: [(KnobPosition, Leds); 8] = [
+    (
+        KnobPosition::Up,
+        leds([0, 0, 1, 0, 1, 1], [1, 1, 1, 1, 0, 1]),
+    ),
+    (
+        KnobPosition::Up,
+        leds([1, 0, 1, 0, 1, 0], [0, 1, 1, 0, 1, 1]),
+    ),
+    (
+        KnobPosition::Down,
+        leds([0, 1, 1, 0, 0, 1], [1, 1, 1, 1, 0, 1]),
+    ),
+    (
+        KnobPosition::Down,
+        leds([1, 0, 1, 0, 1, 0], [0, 1, 0, 0, 0, 1]),
+    ),
+    (
+        KnobPosition::Left,
+        leds([0, 0, 0, 0, 1, 0], [1, 0, 0, 1, 1, 1]),
+    ),
+    (
+        KnobPosition::Left,
+        leds([0, 0, 0, 0, 1, 0], [0, 0, 0, 1, 1, 0]),
+    ),
+    (
+        KnobPosition::Right,
+        leds([1, 0, 1, 1, 1, 1], [1, 1, 1, 0, 1, 0]),
+    ),
+    (
+        KnobPosition::Right,
+        leds([1, 0, 1, 1, 0, 0], [1, 1, 1, 0, 1, 0]),
+    ),
+];
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum KnobsError {
+    /// The lit LEDs do not match any pattern.
+    NoMatch,
+    /// The lit LEDs are one short of patterns for different positions.
+    Ambiguous,
+}
+
+impl fmt::Display for KnobsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KnobsError::NoMatch => write!(f, "the LEDs do not match any pattern"),
+            KnobsError::Ambiguous => {
+                write!(f, "the LEDs match patterns for different positions")
+            }
+        }
+    }
+}
+
+impl std::error::Error for KnobsError {}
+
+/// The position to turn the knob to for the lit LEDs. When nothing matches
+/// exactly, a pattern with one more lit LED is accepted, since a single LED is
+/// easy to misread as off.
+pub fn solve(leds: &Leds) -> Result<KnobPosition, KnobsError> {
+    if let Some((position, _)) = PATTERNS.iter().find(|(_, pattern)| pattern == leds) {
+        return Ok(*position);
+    }
+    let mut positions = PATTERNS.iter().filter_map(|(position, pattern)| {
+        let pairs = || pattern.iter().flatten().zip(leds.iter().flatten());
+        let extra = pairs().filter(|(p, l)| **p && !**l).count();
+        let unexpected = pairs().any(|(p, l)| !*p && *l);
+        (extra == 1 && !unexpected).then_some(*position)
+    });
+    let position = positions.next().ok_or(KnobsError::NoMatch)?;
+    if positions.all(|other| other == position) {
+        Ok(position)
+    } else {
+        Err(KnobsError::Ambiguous)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn patterns() {
+        for (position, pattern) in PATTERNS {
+            assert_eq!(solve(&pattern), Ok(position));
+        }
+        assert_eq!(solve(&[[false; 6]; 2]), Err(KnobsError::NoMatch));
+    }
+
+    #[test]
+    fn one_led_off() {
+        let (position, mut pattern) = PATTERNS[6];
+        pattern[0][0] = false;
+        assert_eq!(solve(&pattern), Ok(position));
+
+        let pattern = leds([1, 0, 1, 0, 1, 0], [0, 1, 0, 0, 1, 1]);
+        assert_eq!(solve(&pattern), Ok(KnobPosition::Up));
+
+        // One short of both the first Up and the first Down pattern.
+        let pattern = leds([0, 0, 1, 0, 0, 1], [1, 1, 1, 1, 0, 1]);
+        assert_eq!(solve(&pattern), Err(KnobsError::Ambiguous));
+    }
+}
@@ -0,0 +1,385 @@
+use std::collections::VecDeque;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use strum_macros::{AsRefStr, EnumIter};
+
+/// A cell on the 6x6 grid, counted from the top left starting at 0.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
+pub struct Cell {
+    pub column: u8,
+    pub row: u8,
+}
+
+impl Cell {
+    /// The neighbouring cell in `direction`, if it is on the grid.
+    pub fn step(&self, direction: Direction) -> Option<Cell> {
+        let (column, row) = match direction {
+            Direction::Up => (Some(self.column), self.row.checked_sub(1)),
+            Direction::Down => (Some(self.column), Some(self.row + 1)),
+            Direction::Left => (self.column.checked_sub(1), Some(self.row)),
+            Direction::Right => (Some(self.column + 1), Some(self.row)),
+        };
+        match (column, row) {
+            (Some(column), Some(row)) if column < 6 && row < 6 => Some(Cell { column, row }),
+            _ => None,
+        }
+    }
+}
+
+#[derive(EnumIter, AsRefStr, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+/// A maze from the manual, identified by its two circle markers.
+pub struct Maze {
+    pub markers: [Cell; 2],
+    /// The walls drawn as text: `+` corners, `--` and `|` walls.
+    layout: [&'static str; 13],
+}
+
+impl Maze {
+    /// Whether a single step from `cell` in `direction` stays inside the grid
+    /// without crossing a wall.
+    pub fn can_move(&self, cell: Cell, direction: Direction) -> bool {
+        let (column, row) = (cell.column as usize, cell.row as usize);
+        if column >= 6 || row >= 6 {
+            return false;
+        }
+        let (line, index) = match direction {
+            Direction::Up => (2 * row, 3 * column + 1),
+            Direction::Down => (2 * row + 2, 3 * column + 1),
+            Direction::Left => (2 * row + 1, 3 * column),
+            Direction::Right => (2 * row + 1, 3 * column + 3),
+        };
+        self.layout[line].as_bytes()[index] == b' '
+    }
+}
+
+/// The nine mazes in the order they appear in the manual.
+pub const MAZES: [Maze; 9] = [
+    Maze {
+        markers: [Cell { column: 0, row: 1 }, Cell { column: 5, row: 2 }],
+        layout: [
+            "+--+--+--+--+--+--+",
+            "|        |        |",
+            "+  +--+  +  +--+--+",
+            "|  |     |        |",
+            "+  +  +--+--+--+  +",
+            "|  |     |        |",
+            "+  +--+  +  +--+  +",
+            "|  |        |     |",
+            "+  +--+--+--+--+  +",
+            "|        |     |  |",
+            "+  +--+  +  +--+  +",
+            "|     |     |     |",
+            "+--+--+--+--+--+--+",
+        ],
+    },
+    Maze {
+        markers: [Cell { column: 4, row: 1 }, Cell { column: 1, row: 3 }],
+        layout: [
+            "+--+--+--+--+--+--+",
+            "|        |        |",
+            "+--+  +--+  +  +--+",
+            "|     |     |     |",
+            "+  +--+  +--+--+  +",
+            "|  |     |        |",
+            "+  +  +--+  +--+  +",
+            "|     |     |  |  |",
+            "+  +--+  +--+  +  +",
+            "|  |  |  |     |  |",
+            "+  +  +  +  +--+  +",
+            "|  |     |        |",
+            "+--+--+--+--+--+--+",
+        ],
+    },
+    Maze {
+        markers: [Cell { column: 3, row: 3 }, Cell { column: 5, row: 3 }],
+        layout: [
+            "+--+--+--+--+--+--+",
+            "|        |  |     |",
+            "+  +--+  +  +  +  +",
+            "|  |  |  |     |  |",
+            "+--+  +  +--+--+  +",
+            "|     |  |     |  |",
+            "+  +  +  +  +  +  +",
+            "|  |  |  |  |  |  |",
+            "+  +  +  +  +  +  +",
+            "|  |     |  |  |  |",
+            "+  +--+--+  +  +  +",
+            "|           |     |",
+            "+--+--+--+--+--+--+",
+        ],
+    },
+    Maze {
+        markers: [Cell { column: 0, row: 0 }, Cell { column: 0, row: 3 }],
+        layout: [
+            "+--+--+--+--+--+--+",
+            "|     |           |",
+            "+  +  +--+--+--+  +",
+            "|  |  |           |",
+            "+  +  +  +--+--+  +",
+            "|  |     |     |  |",
+            "+  +--+--+  +--+  +",
+            "|  |              |",
+            "+  +--+--+--+--+  +",
+            "|              |  |",
+            "+  +--+--+--+  +  +",
+            "|        |     |  |",
+            "+--+--+--+--+--+--+",
+        ],
+    },
+    Maze {
+        markers: [Cell { column: 4, row: 2 }, Cell { column: 3, row: 5 }],
+        layout: [
+            "+--+--+--+--+--+--+",
+            "|                 |",
+            "+--+--+--+--+  +  +",
+            "|              |  |",
+            "+  +--+--+  +--+--+",
+            "|     |     |     |",
+            "+  +  +--+--+  +  +",
+            "|  |        |  |  |",
+            "+  +--+--+  +--+  +",
+            "|  |           |  |",
+            "+  +  +--+--+--+  +",
+            "|  |              |",
+            "+--+--+--+--+--+--+",
+        ],
+    },
+    Maze {
+        markers: [Cell { column: 4, row: 0 }, Cell { column: 2, row: 4 }],
+        layout: [
+            "+--+--+--+--+--+--+",
+            "|  |     |        |",
+            "+  +  +  +--+  +  +",
+            "|  |  |  |     |  |",
+            "+  +  +  +  +--+  +",
+            "|     |  |  |     |",
+            "+  +--+--+  +  +--+",
+            "|     |     |  |  |",
+            "+--+  +  +  +  +  +",
+            "|     |  |  |     |",
+            "+  +--+--+  +--+  +",
+            "|           |     |",
+            "+--+--+--+--+--+--+",
+        ],
+    },
+    Maze {
+        markers: [Cell { column: 1, row: 0 }, Cell { column: 1, row: 5 }],
+        layout: [
+            "+--+--+--+--+--+--+",
+            "|           |     |",
+            "+  +--+--+  +  +  +",
+            "|  |     |     |  |",
+            "+  +  +--+--+--+  +",
+            "|     |     |     |",
+            "+--+--+  +--+  +--+",
+            "|     |        |  |",
+            "+  +  +  +--+--+  +",
+            "|  |  |        |  |",
+            "+  +--+--+--+  +  +",
+            "|                 |",
+            "+--+--+--+--+--+--+",
+        ],
+    },
+    Maze {
+        markers: [Cell { column: 3, row: 0 }, Cell { column: 2, row: 3 }],
+        layout: [
+            "+--+--+--+--+--+--+",
+            "|  |        |     |",
+            "+  +  +--+  +  +  +",
+            "|        |     |  |",
+            "+  +--+--+--+--+  +",
+            "|  |           |  |",
+            "+  +  +--+--+  +  +",
+            "|  |     |        |",
+            "+  +--+  +--+--+--+",
+            "|  |  |           |",
+            "+  +  +--+--+--+--+",
+            "|                 |",
+            "+--+--+--+--+--+--+",
+        ],
+    },
+    Maze {
+        markers: [Cell { column: 2, row: 1 }, Cell { column: 0, row: 4 }],
+        layout: [
+            "+--+--+--+--+--+--+",
+            "|  |              |",
+            "+  +  +--+--+  +  +",
+            "|  |  |     |  |  |",
+            "+  +  +  +--+  +  +",
+            "|        |     |  |",
+            "+  +--+--+  +--+  +",
+            "|  |  |     |     |",
+            "+  +  +  +--+--+  +",
+            "|  |  |  |     |  |",
+            "+  +  +  +  +  +--+",
+            "|     |     |     |",
+            "+--+--+--+--+--+--+",
+        ],
+    },
+];
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum MazeError {
+    /// The cell is outside the 6x6 grid.
+    OutOfBounds(Cell),
+    /// No maze has a circle marker in the cell.
+    NoMaze(Cell),
+}
+
+impl fmt::Display for MazeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MazeError::OutOfBounds(cell) => {
+                write!(
+                    f,
+                    "cell ({}, {}) is outside the maze",
+                    cell.column, cell.row
+                )
+            }
+            MazeError::NoMaze(cell) => {
+                write!(f, "no maze has a marker at ({}, {})", cell.column, cell.row)
+            }
+        }
+    }
+}
+
+impl std::error::Error for MazeError {}
+
+/// Finds the index into [`MAZES`] of the maze with a circle marker in `marker`.
+pub fn identify(marker: Cell) -> Result<usize, MazeError> {
+    MAZES
+        .iter()
+        .position(|maze| maze.markers.contains(&marker))
+        .ok_or(MazeError::NoMaze(marker))
+}
+
+/// The shortest sequence of moves from `start` to `goal` in `maze`.
+pub fn solve(maze: &Maze, start: Cell, goal: Cell) -> Result<Vec<Direction>, MazeError> {
+    for cell in [start, goal] {
+        if cell.column >= 6 || cell.row >= 6 {
+            return Err(MazeError::OutOfBounds(cell));
+        }
+    }
+
+    // Breadth-first search from the start, remembering how each cell was
+    // reached so the path can be walked back from the goal.
+    let mut previous: [[Option<(Cell, Direction)>; 6]; 6] = [[None; 6]; 6];
+    let mut queue = VecDeque::from([start]);
+    while let Some(cell) = queue.pop_front() {
+        if cell == goal {
+            break;
+        }
+        for direction in [
+            Direction::Up,
+            Direction::Down,
+            Direction::Left,
+            Direction::Right,
+        ] {
+            if !maze.can_move(cell, direction) {
+                continue;
+            }
+            let next = cell.step(direction).unwrap();
+            let seen = &mut previous[next.row as usize][next.column as usize];
+            if next != start && seen.is_none() {
+                *seen = Some((cell, direction));
+                queue.push_back(next);
+            }
+        }
+    }
+
+    let mut path = vec![];
+    let mut cell = goal;
+    while let Some((from, direction)) = previous[cell.row as usize][cell.column as usize] {
+        path.push(direction);
+        cell = from;
+    }
+    path.reverse();
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Direction::*;
+    use super::*;
+
+    #[test]
+    fn walls_are_symmetric_and_bounded() {
+        for maze in &MAZES {
+            for row in 0..6 {
+                for column in 0..6 {
+                    let cell = Cell { column, row };
+                    for (direction, opposite) in
+                        [(Up, Down), (Down, Up), (Left, Right), (Right, Left)]
+                    {
+                        match cell.step(direction) {
+                            Some(next) => {
+                                assert_eq!(
+                                    maze.can_move(cell, direction),
+                                    maze.can_move(next, opposite)
+                                )
+                            }
+                            None => assert!(!maze.can_move(cell, direction)),
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn every_cell_is_reachable() {
+        let start = Cell { column: 0, row: 0 };
+        for maze in &MAZES {
+            for row in 0..6 {
+                for column in 0..6 {
+                    let goal = Cell { column, row };
+                    let path = solve(maze, start, goal).unwrap();
+                    assert_eq!(path.is_empty(), goal == start);
+                    let end = path.iter().fold(start, |cell, direction| {
+                        assert!(maze.can_move(cell, *direction));
+                        cell.step(*direction).unwrap()
+                    });
+                    assert_eq!(end, goal);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn identifies_mazes() {
+        for (i, maze) in MAZES.iter().enumerate() {
+            for marker in maze.markers {
+                assert_eq!(identify(marker), Ok(i));
+            }
+        }
+        let cell = Cell { column: 0, row: 0 };
+        assert_eq!(
+            identify(Cell { column: 5, row: 5 }),
+            Err(MazeError::NoMaze(Cell { column: 5, row: 5 }))
+        );
+        assert_eq!(identify(cell), Ok(3));
+    }
+
+    #[test]
+    fn shortest_path() {
+        let path = solve(
+            &MAZES[0],
+            Cell { column: 0, row: 0 },
+            Cell { column: 2, row: 0 },
+        );
+        assert_eq!(path, Ok(vec![Right, Right]));
+        let cell = Cell { column: 6, row: 0 };
+        assert_eq!(
+            solve(&MAZES[0], cell, cell),
+            Err(MazeError::OutOfBounds(cell))
+        );
+    }
+}
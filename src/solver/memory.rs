@@ -0,0 +1,204 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// The button pressed in a completed stage. Positions and labels are 1 to 4,
+/// or `None` when the value was not recorded.
+///
+/// Later stages only refer to the positions from stages 1 and 2 and the labels
+/// from stages 1 to 4.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+pub struct Stage {
+    pub position: Option<u8>,
+    pub label: Option<u8>,
+}
+
+/// A button on the module. The position and the label are both 1 to 4.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Button {
+    pub position: u8,
+    pub label: u8,
+}
+
+impl From<Button> for Stage {
+    fn from(button: Button) -> Self {
+        Stage {
+            position: Some(button.position),
+            label: Some(button.label),
+        }
+    }
+}
+
+/// Which button to press in the current stage.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Press {
+    Position(u8),
+    Label(u8),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum MemoryError {
+    /// The display only shows 1 to 4.
+    InvalidDisplay(u8),
+    /// The module only has five stages.
+    Finished,
+    /// A value needed from an earlier stage (1-based) was not recorded.
+    Unrecorded(usize),
+    /// The button labels must be 1 to 4, each appearing once.
+    InvalidLabels([u8; 4]),
+}
+
+impl fmt::Display for MemoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MemoryError::InvalidDisplay(display) => {
+                write!(f, "expected a display of 1 to 4, got {display}")
+            }
+            MemoryError::Finished => write!(f, "all five stages are complete"),
+            MemoryError::Unrecorded(stage) => {
+                write!(
+                    f,
+                    "stage {stage} is missing a value needed by a later stage"
+                )
+            }
+            MemoryError::InvalidLabels(labels) => {
+                write!(
+                    f,
+                    "expected the labels 1 to 4 in some order, got {labels:?}"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for MemoryError {}
+
+/// The button to press in the stage after `history` given the display.
+pub fn press(history: &[Stage], display: u8) -> Result<Press, MemoryError> {
+    if !(1..=4).contains(&display) {
+        return Err(MemoryError::InvalidDisplay(display));
+    }
+    let position = |stage: usize| {
+        history[stage - 1]
+            .position
+            .map(Press::Position)
+            .ok_or(MemoryError::Unrecorded(stage))
+    };
+    let label = |stage: usize| {
+        history[stage - 1]
+            .label
+            .map(Press::Label)
+            .ok_or(MemoryError::Unrecorded(stage))
+    };
+    match (history.len() + 1, display) {
+        (1, 1 | 2) => Ok(Press::Position(2)),
+        (1, 3) => Ok(Press::Position(3)),
+        (1, _) => Ok(Press::Position(4)),
+        (2, 1) => Ok(Press::Label(4)),
+        (2, 3) => Ok(Press::Position(1)),
+        (2, _) => position(1),
+        (3, 1) => label(2),
+        (3, 2) => label(1),
+        (3, 3) => Ok(Press::Position(3)),
+        (3, _) => Ok(Press::Label(4)),
+        (4, 1) => position(1),
+        (4, 2) => Ok(Press::Position(1)),
+        (4, _) => position(2),
+        (5, 1) => label(1),
+        (5, 2) => label(2),
+        (5, 3) => label(4),
+        (5, _) => label(3),
+        _ => Err(MemoryError::Finished),
+    }
+}
+
+/// Resolves the stage after `history` to a concrete button given the display
+/// and the four button labels from left to right.
+pub fn solve(history: &[Stage], display: u8, labels: [u8; 4]) -> Result<Button, MemoryError> {
+    if (1..=4).any(|label| !labels.contains(&label)) {
+        return Err(MemoryError::InvalidLabels(labels));
+    }
+    Ok(match press(history, display)? {
+        Press::Position(position) => Button {
+            position,
+            label: labels[position as usize - 1],
+        },
+        Press::Label(label) => Button {
+            position: labels.iter().position(|l| *l == label).unwrap() as u8 + 1,
+            label,
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn full_module() {
+        let mut history = vec![];
+        for (display, labels, expected) in [
+            (
+                3,
+                [2, 4, 1, 3],
+                Button {
+                    position: 3,
+                    label: 1,
+                },
+            ),
+            (
+                1,
+                [4, 3, 1, 2],
+                Button {
+                    position: 1,
+                    label: 4,
+                },
+            ),
+            (
+                2,
+                [1, 2, 4, 3],
+                Button {
+                    position: 1,
+                    label: 1,
+                },
+            ),
+            (
+                4,
+                [3, 1, 4, 2],
+                Button {
+                    position: 1,
+                    label: 3,
+                },
+            ),
+            (
+                3,
+                [2, 3, 4, 1],
+                Button {
+                    position: 2,
+                    label: 3,
+                },
+            ),
+        ] {
+            let button = solve(&history, display, labels).unwrap();
+            assert_eq!(button, expected);
+            history.push(button.into());
+        }
+        assert_eq!(press(&history, 1), Err(MemoryError::Finished));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(press(&[], 0), Err(MemoryError::InvalidDisplay(0)));
+        assert_eq!(press(&[], 5), Err(MemoryError::InvalidDisplay(5)));
+        assert_eq!(
+            solve(&[], 1, [1, 1, 2, 3]),
+            Err(MemoryError::InvalidLabels([1, 1, 2, 3]))
+        );
+        let history = [Stage {
+            position: None,
+            label: Some(2),
+        }];
+        assert_eq!(press(&history, 2), Err(MemoryError::Unrecorded(1)));
+        assert_eq!(press(&history, 1), Ok(Press::Label(4)));
+    }
+}
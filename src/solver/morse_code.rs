@@ -0,0 +1,215 @@
+use std::fmt;
+
+/// A word the module can transmit and the frequency to respond on.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Word {
+    pub word: &'static str,
+    /// Frequency in kHz, e.g. 3505 for 3.505 MHz.
+    pub frequency: u16,
+}
+
+impl Word {
+    /// The number of right arrow presses from 3.505 MHz to this word.
+    pub fn presses(&self) -> usize {
+        WORDS.iter().position(|word| word == self).unwrap()
+    }
+}
+
+/// All words in order of frequency, so the index is the number of right
+/// presses needed from the starting frequency.
+pub const WORDS: [Word; 16] = [
+    Word {
+        word: "SHELL",
+        frequency: 3505,
+    },
+    Word {
+        word: "HALLS",
+        frequency: 3515,
+    },
+    Word {
+        word: "SLICK",
+        frequency: 3522,
+    },
+    Word {
+        word: "TRICK",
+        frequency: 3532,
+    },
+    Word {
+        word: "BOXES",
+        frequency: 3535,
+    },
+    Word {
+        word: "LEAKS",
+        frequency: 3542,
+    },
+    Word {
+        word: "STROBE",
+        frequency: 3545,
+    },
+    Word {
+        word: "BISTRO",
+        frequency: 3552,
+    },
+    Word {
+        word: "FLICK",
+        frequency: 3555,
+    },
+    Word {
+        word: "BOMBS",
+        frequency: 3565,
+    },
+    Word {
+        word: "BREAK",
+        frequency: 3572,
+    },
+    Word {
+        word: "BRICK",
+        frequency: 3575,
+    },
+    Word {
+        word: "STEAK",
+        frequency: 3582,
+    },
+    Word {
+        word: "STING",
+        frequency: 3592,
+    },
+    Word {
+        word: "VECTOR",
+        frequency: 3595,
+    },
+    Word {
+        word: "BEATS",
+        frequency: 3600,
+    },
+];
+
+const ALPHABET: [(char, &str); 26] = [
+    ('A', ".-"),
+    ('B', "-..."),
+    ('C', "-.-."),
+    ('D', "-.."),
+    ('E', "."),
+    ('F', "..-."),
+    ('G', "--."),
+    ('H', "...."),
+    ('I', ".."),
+    ('J', ".---"),
+    ('K', "-.-"),
+    ('L', ".-.."),
+    ('M', "--"),
+    ('N', "-."),
+    ('O', "---"),
+    ('P', ".--."),
+    ('Q', "--.-"),
+    ('R', ".-."),
+    ('S', "..."),
+    ('T', "-"),
+    ('U', "..-"),
+    ('V', "...-"),
+    ('W', ".--"),
+    ('X', "-..-"),
+    ('Y', "-.--"),
+    ('Z', "--.."),
+];
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum MorseCodeError {
+    /// The dots and dashes are not a letter.
+    UnknownSequence(String),
+}
+
+impl fmt::Display for MorseCodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MorseCodeError::UnknownSequence(sequence) => {
+                write!(f, "\"{sequence}\" is not a letter")
+            }
+        }
+    }
+}
+
+impl std::error::Error for MorseCodeError {}
+
+/// Decodes one letter written with '.' for dots and '-' for dashes.
+pub fn decode(sequence: &str) -> Result<char, MorseCodeError> {
+    ALPHABET
+        .iter()
+        .find(|(_, code)| *code == sequence)
+        .map(|(letter, _)| *letter)
+        .ok_or_else(|| MorseCodeError::UnknownSequence(sequence.to_owned()))
+}
+
+/// The words starting with the letters received after the long pause.
+pub fn solve(letters: &str) -> Vec<Word> {
+    WORDS
+        .iter()
+        .filter(|word| word.word.starts_with(&letters.to_ascii_uppercase()))
+        .copied()
+        .collect()
+}
+
+/// The words the letters could have come from when they were received
+/// starting anywhere in the signal, which loops after a long pause.
+pub fn solve_looped(letters: &str) -> Vec<Word> {
+    let letters = letters.to_ascii_uppercase();
+    WORDS
+        .iter()
+        .filter(|word| {
+            // Enough repeats to contain any rotation of the letters.
+            word.word
+                .repeat(letters.len() / word.word.len() + 2)
+                .contains(&letters)
+        })
+        .copied()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes() {
+        assert_eq!(decode("..."), Ok('S'));
+        assert_eq!(decode("-.-."), Ok('C'));
+        assert_eq!(
+            decode("......"),
+            Err(MorseCodeError::UnknownSequence("......".to_owned()))
+        );
+    }
+
+    #[test]
+    fn narrows_words() {
+        assert_eq!(solve("").len(), 16);
+        assert_eq!(solve("b").len(), 6);
+        assert_eq!(
+            solve("bri"),
+            vec![Word {
+                word: "BRICK",
+                frequency: 3575
+            }]
+        );
+        assert!(solve("x").is_empty());
+    }
+
+    #[test]
+    fn narrows_looped_words() {
+        assert_eq!(solve_looped("").len(), 16);
+        assert_eq!(
+            solve_looped("llshe"),
+            vec![Word {
+                word: "SHELL",
+                frequency: 3505
+            }]
+        );
+        assert_eq!(solve_looped("ckbri")[0].word, "BRICK");
+        assert_eq!(solve_looped("ick").len(), 4);
+        assert_eq!(solve_looped("vectorvec")[0].presses(), 14);
+    }
+
+    #[test]
+    fn sorted_by_frequency() {
+        assert!(WORDS.windows(2).all(|w| w[0].frequency < w[1].frequency));
+    }
+}
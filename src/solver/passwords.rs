@@ -0,0 +1,96 @@
+/// Every password the module can accept.
+pub const WORDS: [&str; 35] = [
+    "ABOUT", "AFTER", "AGAIN", "BELOW", "COULD", "EVERY", "FIRST", "FOUND", "GREAT", "HOUSE",
+    "LARGE", "LEARN", "NEVER", "OTHER", "PLACE", "PLANT", "POINT", "RIGHT", "SMALL", "SOUND",
+    "SPELL", "STILL", "STUDY", "THEIR", "THERE", "THESE", "THING", "THINK", "THREE", "WATER",
+    "WHERE", "WHICH", "WORLD", "WOULD", "WRITE",
+];
+
+/// The passwords consistent with the letters available in each of the five
+/// columns. An empty column has not been entered yet and matches anything.
+pub fn solve<S: AsRef<str>>(columns: &[S; 5]) -> Vec<&'static str> {
+    WORDS
+        .iter()
+        .filter(|word| {
+            word.chars().zip(columns).all(|(c, column)| {
+                let column = column.as_ref();
+                column.is_empty() || column.chars().any(|l| l.eq_ignore_ascii_case(&c))
+            })
+        })
+        .copied()
+        .collect()
+}
+
+/// For each column not entered yet, the number of passwords expected to remain
+/// once it is, treating every remaining password as equally likely. This
+/// assumes the other letters in the column rule out every password that does
+/// not share the right letter, so it favors columns whose letters differ most.
+pub fn expected_remaining<S: AsRef<str>>(columns: &[S; 5]) -> [Option<f32>; 5] {
+    let candidates = solve(columns);
+    let mut expected = [None; 5];
+    for (i, column) in columns.iter().enumerate() {
+        if !column.as_ref().is_empty() || candidates.is_empty() {
+            continue;
+        }
+        let mut groups: Vec<(u8, usize)> = vec![];
+        for word in &candidates {
+            let letter = word.as_bytes()[i];
+            match groups.iter_mut().find(|(l, _)| *l == letter) {
+                Some((_, count)) => *count += 1,
+                None => groups.push((letter, 1)),
+            }
+        }
+        let squares: usize = groups.iter().map(|(_, count)| count * count).sum();
+        expected[i] = Some(squares as f32 / candidates.len() as f32);
+    }
+    expected
+}
+
+/// The column to ask for next to narrow the passwords down the most, or
+/// `None` once one password is left or every column is entered.
+pub fn best_column<S: AsRef<str>>(columns: &[S; 5]) -> Option<usize> {
+    if solve(columns).len() <= 1 {
+        return None;
+    }
+    expected_remaining(columns)
+        .iter()
+        .enumerate()
+        .filter_map(|(i, expected)| expected.map(|expected| (i, expected)))
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(i, _)| i)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filters() {
+        assert_eq!(solve(&[""; 5]).len(), 35);
+        assert_eq!(solve(&["T", "H", "I", "N", ""]), vec!["THING", "THINK"]);
+        assert_eq!(solve(&["T", "H", "I", "N", "xk"]), vec!["THINK"]);
+        assert_eq!(solve(&["wxyz", "", "", "", "e"]), vec!["WHERE", "WRITE"]);
+        assert!(solve(&["Q", "", "", "", ""]).is_empty());
+    }
+
+    #[test]
+    fn suggests_columns() {
+        let expected = expected_remaining(&["T", "", "", "", ""]);
+        assert_eq!(expected[0], None);
+        // THEIR, THERE, THESE, THING, THINK and THREE all share the second H.
+        assert_eq!(expected[1], Some(6.0));
+        // I, R, S, N, N, E.
+        assert_eq!(expected[3], Some(8.0 / 6.0));
+        assert_eq!(best_column(&["T", "", "", "", ""]), Some(3));
+        assert_eq!(best_column(&["T", "H", "I", "N", "K"]), None);
+        assert_eq!(best_column(&["T", "H", "I", "N", ""]), Some(4));
+        assert_eq!(best_column(&["Q", "", "", "", ""]), None);
+    }
+
+    #[test]
+    fn unique() {
+        for word in WORDS {
+            assert_eq!(WORDS.iter().filter(|w| **w == word).count(), 1, "{word}");
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use strum_macros::{AsRefStr, EnumIter};
+
+#[derive(EnumIter, AsRefStr, Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum SimonColor {
+    Red,
+    Blue,
+    Green,
+    Yellow,
+}
+
+const TABLE: [SimonColor; 24] = [
+    SimonColor::Blue, // No vowel, 0 strikes
+    SimonColor::Yellow,
+    SimonColor::Green,
+    SimonColor::Red,
+    SimonColor::Red, // No vowel, 1 strike
+    SimonColor::Blue,
+    SimonColor::Yellow,
+    SimonColor::Green,
+    SimonColor::Yellow, // No vowel, 2 strikes
+    SimonColor::Green,
+    SimonColor::Blue,
+    SimonColor::Red,
+    SimonColor::Blue, // Vowel, 0 strikes
+    SimonColor::Red,
+    SimonColor::Yellow,
+    SimonColor::Green,
+    SimonColor::Yellow, // Vowel, 1 strike
+    SimonColor::Green,
+    SimonColor::Blue,
+    SimonColor::Red,
+    SimonColor::Green, // Vowel, 2 strikes
+    SimonColor::Red,
+    SimonColor::Yellow,
+    SimonColor::Blue,
+];
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum SimonSaysError {
+    /// The manual only covers 0, 1 and 2 strikes.
+    TooManyStrikes(u8),
+}
+
+impl fmt::Display for SimonSaysError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SimonSaysError::TooManyStrikes(strikes) => {
+                write!(f, "expected 0 to 2 strikes, got {strikes}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for SimonSaysError {}
+
+/// The button to press for a single flashed color.
+pub fn translate(
+    color: SimonColor,
+    vowel: bool,
+    strikes: u8,
+) -> Result<SimonColor, SimonSaysError> {
+    if strikes > 2 {
+        return Err(SimonSaysError::TooManyStrikes(strikes));
+    }
+    Ok(TABLE[(if vowel { 12 } else { 0 }
+        + strikes * 4
+        + match color {
+            SimonColor::Red => 0,
+            SimonColor::Blue => 1,
+            SimonColor::Green => 2,
+            SimonColor::Yellow => 3,
+        }) as usize])
+}
+
+/// The buttons to press for a whole flash sequence.
+pub fn solve(
+    flashes: &[SimonColor],
+    vowel: bool,
+    strikes: u8,
+) -> Result<Vec<SimonColor>, SimonSaysError> {
+    flashes
+        .iter()
+        .map(|color| translate(*color, vowel, strikes))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SimonColor::*;
+    use super::*;
+
+    #[test]
+    fn sequences() {
+        assert_eq!(
+            solve(&[Red, Blue, Green, Yellow], false, 0),
+            Ok(vec![Blue, Yellow, Green, Red])
+        );
+        assert_eq!(
+            solve(&[Red, Blue, Green, Yellow], true, 1),
+            Ok(vec![Yellow, Green, Blue, Red])
+        );
+        assert_eq!(
+            solve(&[Red, Blue, Green, Yellow], true, 2),
+            Ok(vec![Green, Red, Yellow, Blue])
+        );
+        assert_eq!(solve(&[], true, 2), Ok(vec![]));
+    }
+
+    #[test]
+    fn too_many_strikes() {
+        assert_eq!(
+            translate(Red, false, 3),
+            Err(SimonSaysError::TooManyStrikes(3))
+        );
+    }
+}
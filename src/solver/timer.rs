@@ -0,0 +1,66 @@
+use std::time::Duration;
+
+/// How many times faster than real time the bomb timer runs after `strikes`
+/// strikes.
+pub fn rate(strikes: u8) -> f32 {
+    1.0 + 0.25 * f32::from(strikes.min(4))
+}
+
+/// The timer as the bomb shows it: minutes and seconds, or seconds and
+/// hundredths during the last minute.
+pub fn display(remaining: Duration) -> String {
+    let seconds = remaining.as_secs();
+    if seconds >= 60 {
+        format!("{:02}:{:02}", seconds / 60, seconds % 60)
+    } else {
+        format!("{:02}.{:02}", seconds, remaining.subsec_millis() / 10)
+    }
+}
+
+/// How much longer the bomb shows the same `display(remaining)`.
+pub fn next_change(remaining: Duration) -> Duration {
+    let step = if remaining.as_secs() >= 60 {
+        Duration::from_secs(1)
+    } else {
+        Duration::from_millis(10)
+    };
+    let into = remaining.as_nanos() % step.as_nanos();
+    Duration::from_nanos(into as u64)
+}
+
+/// Whether `digit` appears anywhere on a timer display.
+pub fn shows_digit(display: &str, digit: u8) -> bool {
+    display.contains(char::from(b'0' + digit))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn displays() {
+        assert_eq!(display(Duration::from_secs(301)), "05:01");
+        assert_eq!(display(Duration::from_millis(59_456)), "59.45");
+        assert!(shows_digit("05:01", 1));
+        assert!(!shows_digit("05:01", 4));
+    }
+
+    #[test]
+    fn changes() {
+        assert_eq!(
+            next_change(Duration::from_millis(301_250)),
+            Duration::from_millis(250)
+        );
+        assert_eq!(next_change(Duration::from_secs(301)), Duration::ZERO);
+        assert_eq!(
+            next_change(Duration::from_micros(59_456_500)),
+            Duration::from_micros(6_500)
+        );
+    }
+
+    #[test]
+    fn speeds_up() {
+        assert_eq!(rate(0), 1.0);
+        assert_eq!(rate(2), 1.5);
+    }
+}
@@ -0,0 +1,495 @@
+use std::fmt;
+
+use strum_macros::{AsRefStr, EnumIter};
+
+/// A button position on the module.
+#[derive(EnumIter, AsRefStr, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Position {
+    TopLeft,
+    TopRight,
+    MiddleLeft,
+    MiddleRight,
+    BottomLeft,
+    BottomRight,
+}
+
+impl Position {
+    pub fn name(&self) -> &str {
+        match self {
+            Position::TopLeft => "top left",
+            Position::TopRight => "top right",
+            Position::MiddleLeft => "middle left",
+            Position::MiddleRight => "middle right",
+            Position::BottomLeft => "bottom left",
+            Position::BottomRight => "bottom right",
+        }
+    }
+
+    /// Index of the button, reading left to right and top to bottom.
+    pub fn index(&self) -> usize {
+        *self as usize
+    }
+
+    pub fn from_index(index: usize) -> Option<Position> {
+        [
+            Position::TopLeft,
+            Position::TopRight,
+            Position::MiddleLeft,
+            Position::MiddleRight,
+            Position::BottomLeft,
+            Position::BottomRight,
+        ]
+        .get(index)
+        .copied()
+    }
+}
+
+/// Step 1: the button to read for each display word. An empty display is "".
+pub const DISPLAY_WORDS: [(&str, Position); 28] = [
+    ("", Position::BottomLeft),
+    ("BLANK", Position::MiddleRight),
+    ("C", Position::TopRight),
+    ("CEE", Position::BottomRight),
+    ("DISPLAY", Position::BottomRight),
+    ("FIRST", Position::TopRight),
+    ("HOLD ON", Position::BottomRight),
+    ("LEAD", Position::BottomRight),
+    ("LED", Position::MiddleLeft),
+    ("LEED", Position::BottomLeft),
+    ("NO", Position::BottomRight),
+    ("NOTHING", Position::MiddleLeft),
+    ("OKAY", Position::TopRight),
+    ("READ", Position::MiddleRight),
+    ("RED", Position::MiddleRight),
+    ("REED", Position::BottomLeft),
+    ("SAYS", Position::BottomRight),
+    ("SEE", Position::BottomRight),
+    ("THEIR", Position::MiddleRight),
+    ("THERE", Position::BottomRight),
+    ("THEY ARE", Position::MiddleLeft),
+    ("THEY'RE", Position::BottomLeft),
+    ("UR", Position::TopLeft),
+    ("YES", Position::MiddleLeft),
+    ("YOU", Position::MiddleRight),
+    ("YOU ARE", Position::BottomRight),
+    ("YOU'RE", Position::MiddleRight),
+    ("YOUR", Position::MiddleRight),
+];
+
+/// Step 2: for each button label, the words to look for in priority order.
+///
+/// Each list ends with the label itself, since later words can never be
+/// reached.
+pub const BUTTON_WORDS: [(&str, &[&str]); 28] = [
+    ("BLANK", &["WAIT", "RIGHT", "OKAY", "MIDDLE", "BLANK"]),
+    (
+        "DONE",
+        &[
+            "SURE", "UH HUH", "NEXT", "WHAT?", "YOUR", "UR", "YOU'RE", "HOLD", "LIKE", "YOU", "U",
+            "YOU ARE", "UH UH", "DONE",
+        ],
+    ),
+    (
+        "FIRST",
+        &[
+            "LEFT", "OKAY", "YES", "MIDDLE", "NO", "RIGHT", "NOTHING", "UHHH", "WAIT", "READY",
+            "BLANK", "WHAT", "PRESS", "FIRST",
+        ],
+    ),
+    (
+        "HOLD",
+        &[
+            "YOU ARE", "U", "DONE", "UH UH", "YOU", "UR", "SURE", "WHAT?", "YOU'RE", "NEXT", "HOLD",
+        ],
+    ),
+    ("LEFT", &["RIGHT", "LEFT"]),
+    (
+        "LIKE",
+        &[
+            "YOU'RE", "NEXT", "U", "UR", "HOLD", "DONE", "UH UH", "WHAT?", "UH HUH", "YOU", "LIKE",
+        ],
+    ),
+    (
+        "MIDDLE",
+        &[
+            "BLANK", "READY", "OKAY", "WHAT", "NOTHING", "PRESS", "NO", "WAIT", "LEFT", "MIDDLE",
+        ],
+    ),
+    (
+        "NEXT",
+        &["WHAT?", "UH HUH", "UH UH", "YOUR", "HOLD", "SURE", "NEXT"],
+    ),
+    (
+        "NO",
+        &[
+            "BLANK", "UHHH", "WAIT", "FIRST", "WHAT", "READY", "RIGHT", "YES", "NOTHING", "LEFT",
+            "PRESS", "OKAY", "NO",
+        ],
+    ),
+    (
+        "NOTHING",
+        &[
+            "UHHH", "RIGHT", "OKAY", "MIDDLE", "YES", "BLANK", "NO", "PRESS", "LEFT", "WHAT",
+            "WAIT", "FIRST", "NOTHING",
+        ],
+    ),
+    (
+        "OKAY",
+        &[
+            "MIDDLE", "NO", "FIRST", "YES", "UHHH", "NOTHING", "WAIT", "OKAY",
+        ],
+    ),
+    ("PRESS", &["RIGHT", "MIDDLE", "YES", "READY", "PRESS"]),
+    (
+        "RIGHT",
+        &[
+            "YES", "NOTHING", "READY", "PRESS", "NO", "WAIT", "WHAT", "RIGHT",
+        ],
+    ),
+    (
+        "SURE",
+        &[
+            "YOU ARE", "DONE", "LIKE", "YOU'RE", "YOU", "HOLD", "UH HUH", "UR", "SURE",
+        ],
+    ),
+    (
+        "U",
+        &[
+            "UH HUH", "SURE", "NEXT", "WHAT?", "YOU'RE", "UR", "UH UH", "DONE", "U",
+        ],
+    ),
+    ("UH HUH", &["UH HUH"]),
+    ("UH UH", &["UR", "U", "YOU ARE", "YOU'RE", "NEXT", "UH UH"]),
+    (
+        "UHHH",
+        &[
+            "READY", "NOTHING", "LEFT", "WHAT", "OKAY", "YES", "RIGHT", "NO", "PRESS", "BLANK",
+            "UHHH",
+        ],
+    ),
+    ("UR", &["DONE", "U", "UR"]),
+    (
+        "WAIT",
+        &[
+            "UHHH", "NO", "BLANK", "OKAY", "YES", "LEFT", "FIRST", "PRESS", "WHAT", "WAIT",
+        ],
+    ),
+    ("WHAT", &["UHHH", "WHAT"]),
+    (
+        "WHAT?",
+        &[
+            "YOU", "HOLD", "YOU'RE", "YOUR", "U", "DONE", "UH UH", "LIKE", "YOU ARE", "UH HUH",
+            "UR", "NEXT", "WHAT?",
+        ],
+    ),
+    (
+        "YES",
+        &[
+            "OKAY", "RIGHT", "UHHH", "MIDDLE", "FIRST", "WHAT", "PRESS", "READY", "NOTHING", "YES",
+        ],
+    ),
+    (
+        "YOU ARE",
+        &[
+            "YOUR", "NEXT", "LIKE", "UH HUH", "WHAT?", "DONE", "UH UH", "HOLD", "YOU", "U",
+            "YOU'RE", "SURE", "UR", "YOU ARE",
+        ],
+    ),
+    (
+        "YOU",
+        &[
+            "SURE", "YOU ARE", "YOUR", "YOU'RE", "NEXT", "UH HUH", "UR", "HOLD", "WHAT?", "YOU",
+        ],
+    ),
+    ("YOU'RE", &["YOU", "YOU'RE"]),
+    ("YOUR", &["UH UH", "YOU ARE", "UH HUH", "YOUR"]),
+    (
+        "READY",
+        &[
+            "YES", "OKAY", "WHAT", "MIDDLE", "LEFT", "PRESS", "RIGHT", "BLANK", "READY",
+        ],
+    ),
+];
+
+/// How the defuser spells out each word, one per line with the word in
+/// brackets when it differs from the spelling and a trailing number shared by
+/// words that sound alike.
+const SPELLINGS: &str = include_str!("../../WhosOnFirst.txt");
+
+/// A word as the defuser spells it out.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Spelling {
+    pub spoken: &'static str,
+    pub word: &'static str,
+    /// Words with the same family sound alike and are easily confused.
+    pub family: Option<u8>,
+}
+
+impl Spelling {
+    fn parse(line: &'static str) -> Spelling {
+        let line = line.trim();
+        let (spoken, word, rest) = match line.split_once('[') {
+            Some((spoken, rest)) => {
+                let (word, rest) = rest.split_once(']').unwrap_or((rest, ""));
+                (spoken.trim(), word.trim(), rest.trim())
+            }
+            None => match line.rsplit_once(' ') {
+                Some((word, family)) if family.parse::<u8>().is_ok() => (word, word, family),
+                _ => (line, line, ""),
+            },
+        };
+        Spelling {
+            spoken,
+            word,
+            family: rest.parse().ok(),
+        }
+    }
+}
+
+fn spellings(section: &str) -> Vec<Spelling> {
+    SPELLINGS
+        .lines()
+        .skip_while(|line| line.trim() != section)
+        .skip(1)
+        .take_while(|line| !line.trim().is_empty())
+        .map(Spelling::parse)
+        .collect()
+}
+
+/// Every display word as it is spelled out, in the order of `WhosOnFirst.txt`.
+pub fn display_spellings() -> Vec<Spelling> {
+    spellings("===Displayed===")
+}
+
+/// Every button word as it is spelled out, in the order of `WhosOnFirst.txt`.
+pub fn button_spellings() -> Vec<Spelling> {
+    spellings("===Button===")
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum WhosOnFirstError {
+    UnknownDisplay(String),
+    UnknownButton(String),
+}
+
+impl fmt::Display for WhosOnFirstError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WhosOnFirstError::UnknownDisplay(word) => write!(f, "unknown display word \"{word}\""),
+            WhosOnFirstError::UnknownButton(word) => write!(f, "unknown button word \"{word}\""),
+        }
+    }
+}
+
+impl std::error::Error for WhosOnFirstError {}
+
+/// Shortens a word to its first letter and last three characters, which is
+/// enough to tell every display and button word apart.
+pub fn abbreviate(word: &str) -> String {
+    if word.chars().count() > 4 {
+        let mut s = String::from(word.chars().next().unwrap());
+        s += &word[word.char_indices().nth_back(2).unwrap().0..];
+        s
+    } else {
+        word.to_owned()
+    }
+}
+
+/// Finds the display word with the given abbreviation.
+pub fn display_from_abbreviation(abbreviation: &str) -> Option<&'static str> {
+    DISPLAY_WORDS
+        .iter()
+        .map(|(word, _)| *word)
+        .find(|word| abbreviate(word) == abbreviation)
+}
+
+/// Finds the button word with the given abbreviation.
+pub fn button_from_abbreviation(abbreviation: &str) -> Option<&'static str> {
+    BUTTON_WORDS
+        .iter()
+        .map(|(word, _)| *word)
+        .find(|word| abbreviate(word) == abbreviation)
+}
+
+/// The number of single character insertions, deletions and substitutions
+/// needed to turn `a` into `b`.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, a) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, b) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(a != *b);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(diagonal + 1).min(row[j] + 1);
+        }
+    }
+    row[b.len()]
+}
+
+/// The closest known words to a mistyped one, comparing against both the full
+/// words and their abbreviations. Returns at most three words, nearest first.
+fn suggestions(typed: &str, words: impl Iterator<Item = &'static str>) -> Vec<&'static str> {
+    let typed = typed.to_ascii_uppercase();
+    let mut scored: Vec<_> = words
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let distance =
+                edit_distance(&typed, word).min(edit_distance(&typed, &abbreviate(word)));
+            (distance, word)
+        })
+        .filter(|(distance, _)| *distance <= 2)
+        .collect();
+    scored.sort_by_key(|(distance, _)| *distance);
+    scored.into_iter().take(3).map(|(_, word)| word).collect()
+}
+
+/// The display words closest to a mistyped one.
+pub fn suggest_display(typed: &str) -> Vec<&'static str> {
+    suggestions(typed, DISPLAY_WORDS.iter().map(|(word, _)| *word))
+}
+
+/// The button words closest to a mistyped one.
+pub fn suggest_button(typed: &str) -> Vec<&'static str> {
+    suggestions(typed, BUTTON_WORDS.iter().map(|(word, _)| *word))
+}
+
+/// Step 1: the button whose label should be read for a display word.
+pub fn read_position(display: &str) -> Result<Position, WhosOnFirstError> {
+    DISPLAY_WORDS
+        .iter()
+        .find(|(word, _)| *word == display)
+        .map(|(_, position)| *position)
+        .ok_or_else(|| WhosOnFirstError::UnknownDisplay(display.to_owned()))
+}
+
+/// Step 2: the priority list for a button label.
+pub fn priority_list(label: &str) -> Result<&'static [&'static str], WhosOnFirstError> {
+    BUTTON_WORDS
+        .iter()
+        .find(|(word, _)| *word == label)
+        .map(|(_, list)| *list)
+        .ok_or_else(|| WhosOnFirstError::UnknownButton(label.to_owned()))
+}
+
+/// Finds the button to press given the display word and the six button labels.
+pub fn solve(display: &str, buttons: &[&str; 6]) -> Result<Position, WhosOnFirstError> {
+    if let Some(button) = buttons.iter().find(|button| priority_list(button).is_err()) {
+        return Err(WhosOnFirstError::UnknownButton((*button).to_owned()));
+    }
+    let label = buttons[read_position(display)?.index()];
+    let word = priority_list(label)?
+        .iter()
+        .find(|word| buttons.contains(word))
+        .expect("every priority list ends with its own label");
+    Ok(Position::from_index(buttons.iter().position(|button| button == word).unwrap()).unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn abbreviations_are_unique() {
+        for table in [
+            DISPLAY_WORDS
+                .iter()
+                .map(|(word, _)| *word)
+                .collect::<Vec<_>>(),
+            BUTTON_WORDS
+                .iter()
+                .map(|(word, _)| *word)
+                .collect::<Vec<_>>(),
+        ] {
+            for word in &table {
+                assert_eq!(
+                    table
+                        .iter()
+                        .filter(|w| abbreviate(w) == abbreviate(word))
+                        .count(),
+                    1,
+                    "{word}"
+                );
+            }
+        }
+        assert_eq!(abbreviate("YOU'RE"), "Y'RE");
+        assert_eq!(display_from_abbreviation("TARE"), Some("THEY ARE"));
+        assert_eq!(button_from_abbreviation("UHUH"), Some("UH HUH"));
+    }
+
+    #[test]
+    fn spellings_cover_every_word() {
+        for (spellings, words) in [
+            (
+                display_spellings(),
+                DISPLAY_WORDS.map(|(word, _)| word).to_vec(),
+            ),
+            (
+                button_spellings(),
+                BUTTON_WORDS.map(|(word, _)| word).to_vec(),
+            ),
+        ] {
+            let mut spelled: Vec<_> = spellings.iter().map(|spelling| spelling.word).collect();
+            spelled.sort();
+            let mut words = words;
+            words.sort();
+            assert_eq!(spelled, words);
+        }
+        assert!(display_spellings().contains(&Spelling {
+            spoken: "ROMEO ECHO ALPHA DELTA",
+            word: "READ",
+            family: Some(2),
+        }));
+        assert!(button_spellings().contains(&Spelling {
+            spoken: "YOU",
+            word: "YOU",
+            family: Some(1),
+        }));
+        assert!(button_spellings().contains(&Spelling {
+            spoken: "BLANK",
+            word: "BLANK",
+            family: None,
+        }));
+    }
+
+    #[test]
+    fn suggests_nearest_words() {
+        assert_eq!(edit_distance("", "ABC"), 3);
+        assert_eq!(edit_distance("KITTEN", "SITTING"), 3);
+        assert_eq!(edit_distance("READY", "READY"), 0);
+        assert_eq!(suggest_display("DISPLY"), vec!["DISPLAY"]);
+        assert_eq!(suggest_display("NOTHNG")[0], "NOTHING");
+        assert_eq!(suggest_button("MIDLE"), vec!["MIDDLE"]);
+        assert_eq!(suggest_button("QQQQQQQQ"), Vec::<&str>::new());
+        assert!(suggest_button("UHH").contains(&"UHHH"));
+    }
+
+    #[test]
+    fn priority_lists_only_contain_button_words() {
+        for (label, list) in BUTTON_WORDS {
+            assert_eq!(list.last(), Some(&label));
+            for word in list {
+                assert!(priority_list(word).is_ok(), "{word}");
+            }
+        }
+    }
+
+    #[test]
+    fn solves() {
+        let buttons = ["READY", "NO", "YES", "WAIT", "BLANK", "FIRST"];
+        // Display "YES" reads middle left, "YES", whose list reaches "FIRST".
+        assert_eq!(solve("YES", &buttons), Ok(Position::BottomRight));
+        // Display "UR" reads top left, which is "READY".
+        assert_eq!(solve("UR", &buttons), Ok(Position::MiddleLeft));
+        assert_eq!(
+            solve("NOPE", &buttons),
+            Err(WhosOnFirstError::UnknownDisplay("NOPE".to_owned()))
+        );
+        let buttons = ["READY", "NO", "YES", "WAIT", "BLANK", "NOPE"];
+        assert_eq!(
+            solve("YES", &buttons),
+            Err(WhosOnFirstError::UnknownButton("NOPE".to_owned()))
+        );
+    }
+}
@@ -0,0 +1,158 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use strum_macros::{AsRefStr, EnumIter};
+
+#[derive(EnumIter, AsRefStr, Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum SequenceColor {
+    Red,
+    Blue,
+    Black,
+}
+
+/// The letter a wire is connected to on the right side of a panel.
+#[derive(EnumIter, AsRefStr, Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum Terminal {
+    A,
+    B,
+    C,
+}
+
+use Terminal::{A, B, C};
+
+/// A wire on the left side of a panel.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct Wire {
+    pub color: SequenceColor,
+    pub terminal: Terminal,
+}
+
+/// The three wire positions of a panel, which may be empty.
+pub type Panel = [Option<Wire>; 3];
+
+/// For each color, the terminals to cut at on each occurrence of that color.
+const TABLE: [[&[Terminal]; 9]; 3] = [
+    [
+        &[C],
+        &[B],
+        &[A],
+        &[A, C],
+        &[B],
+        &[A, C],
+        &[A, B, C],
+        &[A, B],
+        &[B],
+    ],
+    [
+        &[B],
+        &[A, C],
+        &[B],
+        &[A],
+        &[B],
+        &[B, C],
+        &[C],
+        &[A, C],
+        &[A],
+    ],
+    [
+        &[A, B, C],
+        &[A, C],
+        &[B],
+        &[A, C],
+        &[B],
+        &[B, C],
+        &[A, B],
+        &[C],
+        &[C],
+    ],
+];
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum WireSequenceError {
+    /// The module never has more than nine wires of one color.
+    TooManyWires(SequenceColor),
+}
+
+impl fmt::Display for WireSequenceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WireSequenceError::TooManyWires(color) => {
+                write!(f, "more than nine {} wires", color.as_ref().to_lowercase())
+            }
+        }
+    }
+}
+
+impl std::error::Error for WireSequenceError {}
+
+/// The terminals to cut at for the zero-based `occurrence` of a color.
+pub fn targets(
+    color: SequenceColor,
+    occurrence: usize,
+) -> Result<&'static [Terminal], WireSequenceError> {
+    TABLE[color as usize]
+        .get(occurrence)
+        .copied()
+        .ok_or(WireSequenceError::TooManyWires(color))
+}
+
+/// Whether to cut the zero-based `occurrence` of a color connected to `terminal`.
+pub fn solve(
+    color: SequenceColor,
+    occurrence: usize,
+    terminal: Terminal,
+) -> Result<bool, WireSequenceError> {
+    Ok(targets(color, occurrence)?.contains(&terminal))
+}
+
+/// Whether to cut each wire of the last of `panels`, counting the wires of
+/// the earlier panels.
+pub fn solve_panel(panels: &[Panel]) -> Result<[Option<bool>; 3], WireSequenceError> {
+    let mut counts = [0; 3];
+    let mut cuts = [None; 3];
+    for panel in panels {
+        for (wire, cut) in panel.iter().zip(cuts.iter_mut()) {
+            *cut = match wire {
+                Some(wire) => {
+                    let count = &mut counts[wire.color as usize];
+                    *count += 1;
+                    Some(solve(wire.color, *count - 1, wire.terminal)?)
+                }
+                None => None,
+            };
+        }
+    }
+    Ok(cuts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SequenceColor::*;
+    use super::*;
+
+    #[test]
+    fn cuts() {
+        assert_eq!(solve(Red, 0, C), Ok(true));
+        assert_eq!(solve(Red, 0, A), Ok(false));
+        assert_eq!(solve(Blue, 1, C), Ok(true));
+        assert_eq!(solve(Black, 0, B), Ok(true));
+        assert_eq!(targets(Black, 8), Ok(&[C][..]));
+    }
+
+    #[test]
+    fn panels() {
+        let wire = |color, terminal| Some(Wire { color, terminal });
+        let first = [wire(Red, C), None, wire(Blue, A)];
+        let second = [wire(Red, C), wire(Red, A), wire(Black, A)];
+        assert_eq!(solve_panel(&[first]), Ok([Some(true), None, Some(false)]));
+        assert_eq!(
+            solve_panel(&[first, second]),
+            Ok([Some(false), Some(true), Some(true)])
+        );
+    }
+
+    #[test]
+    fn too_many() {
+        assert_eq!(targets(Blue, 9), Err(WireSequenceError::TooManyWires(Blue)));
+    }
+}
@@ -0,0 +1,225 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use strum_macros::{AsRefStr, EnumIter};
+
+#[derive(EnumIter, AsRefStr, Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum WireColor {
+    Red,
+    Blue,
+    Yellow,
+    White,
+    Black,
+}
+
+/// The wire to cut and the rule that selected it.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Solution {
+    /// Zero-based index of the wire to cut.
+    pub wire: usize,
+    /// Index into [`rules`] of the first rule that applied.
+    pub rule: usize,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum WiresError {
+    /// The module always has between 3 and 6 wires.
+    WrongCount(usize),
+}
+
+impl fmt::Display for WiresError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WiresError::WrongCount(count) => {
+                write!(f, "expected 3 to 6 wires, got {count}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for WiresError {}
+
+const RULES: [&[&str]; 4] = [
+    &[
+        "0 red: 2",
+        "last is white: last",
+        "2+ blue: last blue",
+        "else: last",
+    ],
+    &[
+        "2+ red & serial number ends odd: last red",
+        "0 red & last is yellow: 1",
+        "1 blue: 1",
+        "2+ yellow: last",
+        "else: 2",
+    ],
+    &[
+        "last is black & serial number ends odd: 4",
+        "1 red & 2+ yellow: 1",
+        "0 black: 2",
+        "else: 1",
+    ],
+    &[
+        "0 yellow & serial number ends odd: 3",
+        "1 yellow & 2+ white: 4",
+        "0 red: last",
+        "else: 4",
+    ],
+];
+
+/// The rules for a module with `count` wires, in the order they are checked.
+pub fn rules(count: usize) -> Result<&'static [&'static str], WiresError> {
+    match count {
+        3..=6 => Ok(RULES[count - 3]),
+        _ => Err(WiresError::WrongCount(count)),
+    }
+}
+
+/// Finds the wire to cut given the wire colors from top to bottom.
+pub fn solve(wires: &[WireColor], serial_odd: bool) -> Result<Solution, WiresError> {
+    let count = |color| wires.iter().filter(|w| **w == color).count();
+    let last_of = |color| wires.iter().rposition(|w| *w == color).unwrap();
+    let last = wires.len().saturating_sub(1);
+    let last_color = wires.last().copied();
+    let red = count(WireColor::Red);
+    let blue = count(WireColor::Blue);
+    let yellow = count(WireColor::Yellow);
+    let white = count(WireColor::White);
+    let black = count(WireColor::Black);
+
+    let (wire, rule) = match wires.len() {
+        3 => {
+            if red == 0 {
+                (1, 0)
+            } else if last_color == Some(WireColor::White) {
+                (last, 1)
+            } else if blue > 1 {
+                (last_of(WireColor::Blue), 2)
+            } else {
+                (last, 3)
+            }
+        }
+        4 => {
+            if red > 1 && serial_odd {
+                (last_of(WireColor::Red), 0)
+            } else if red == 0 && last_color == Some(WireColor::Yellow) {
+                (0, 1)
+            } else if blue == 1 {
+                (0, 2)
+            } else if yellow > 1 {
+                (last, 3)
+            } else {
+                (1, 4)
+            }
+        }
+        5 => {
+            if last_color == Some(WireColor::Black) && serial_odd {
+                (3, 0)
+            } else if red == 1 && yellow > 1 {
+                (0, 1)
+            } else if black == 0 {
+                (1, 2)
+            } else {
+                (0, 3)
+            }
+        }
+        6 => {
+            if yellow == 0 && serial_odd {
+                (2, 0)
+            } else if yellow == 1 && white > 1 {
+                (3, 1)
+            } else if red == 0 {
+                (last, 2)
+            } else {
+                (3, 3)
+            }
+        }
+        count => return Err(WiresError::WrongCount(count)),
+    };
+    Ok(Solution { wire, rule })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WireColor::*;
+    use super::*;
+
+    #[test]
+    fn three_wires() {
+        assert_eq!(
+            solve(&[Blue, Blue, White], false),
+            Ok(Solution { wire: 1, rule: 0 })
+        );
+        assert_eq!(
+            solve(&[Red, Blue, White], false),
+            Ok(Solution { wire: 2, rule: 1 })
+        );
+        assert_eq!(
+            solve(&[Blue, Blue, Red], false),
+            Ok(Solution { wire: 1, rule: 2 })
+        );
+        assert_eq!(
+            solve(&[Red, Blue, Black], false),
+            Ok(Solution { wire: 2, rule: 3 })
+        );
+    }
+
+    #[test]
+    fn four_wires() {
+        assert_eq!(
+            solve(&[Red, Red, Blue, Blue], true),
+            Ok(Solution { wire: 1, rule: 0 })
+        );
+        assert_eq!(
+            solve(&[Red, Red, Blue, Blue], false),
+            Ok(Solution { wire: 1, rule: 4 })
+        );
+        assert_eq!(
+            solve(&[Blue, Black, Black, Yellow], false),
+            Ok(Solution { wire: 0, rule: 1 })
+        );
+        assert_eq!(
+            solve(&[Red, Yellow, Yellow, White], false),
+            Ok(Solution { wire: 3, rule: 3 })
+        );
+    }
+
+    #[test]
+    fn five_wires() {
+        assert_eq!(
+            solve(&[Red, Red, Red, Red, Black], true),
+            Ok(Solution { wire: 3, rule: 0 })
+        );
+        assert_eq!(
+            solve(&[Red, Yellow, Yellow, Black, Black], false),
+            Ok(Solution { wire: 0, rule: 1 })
+        );
+        assert_eq!(
+            solve(&[Red, Red, Blue, Blue, White], false),
+            Ok(Solution { wire: 1, rule: 2 })
+        );
+        assert_eq!(
+            solve(&[Red, Red, Blue, Black, White], false),
+            Ok(Solution { wire: 0, rule: 3 })
+        );
+    }
+
+    #[test]
+    fn six_wires() {
+        assert_eq!(solve(&[Red; 6], true), Ok(Solution { wire: 2, rule: 0 }));
+        assert_eq!(
+            solve(&[Yellow, White, White, Red, Red, Red], true),
+            Ok(Solution { wire: 3, rule: 1 })
+        );
+        assert_eq!(solve(&[Blue; 6], false), Ok(Solution { wire: 5, rule: 2 }));
+        assert_eq!(solve(&[Red; 6], false), Ok(Solution { wire: 3, rule: 3 }));
+    }
+
+    #[test]
+    fn wrong_count() {
+        assert_eq!(solve(&[Red, Red], false), Err(WiresError::WrongCount(2)));
+        assert_eq!(solve(&[Red; 7], false), Err(WiresError::WrongCount(7)));
+        assert!(rules(2).is_err());
+        assert_eq!(rules(4).unwrap().len(), 5);
+    }
+}